@@ -16,3 +16,30 @@ where
         (self.0.align(&other.0), self.1.align(&other.1))
     }
 }
+
+impl<T, U, V> Align<T, (U, U, U)> for (V, V, V)
+where
+    T: Float,
+    V: Align<T, U>,
+{
+    #[inline]
+    fn align(self, other: &(U, U, U)) -> Self {
+        (
+            self.0.align(&other.0),
+            self.1.align(&other.1),
+            self.2.align(&other.2),
+        )
+    }
+}
+
+impl<T, U, V, const N: usize> Align<T, [U; N]> for [V; N]
+where
+    T: Float,
+    V: Align<T, U>,
+{
+    #[inline]
+    fn align(self, other: &[U; N]) -> Self {
+        let mut other = other.iter();
+        self.map(|curve| curve.align(other.next().unwrap()))
+    }
+}
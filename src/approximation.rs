@@ -26,6 +26,13 @@ where
             time: one / (one + one),
         }
     }
+
+    /// Check if the process has run to completion as opposed to having been stopped by the
+    /// goodness of fit.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.curves.is_empty()
+    }
 }
 
 impl<T, U, V> Iterator for Approximation<T, U, V>
@@ -41,7 +48,11 @@ where
         while let Some(original) = self.curves.pop() {
             let reduced = original.reduce().align(&original);
             let expanded = reduced.expand();
-            if self.goodness.admit(&original, &expanded)? {
+            let Some(admitted) = self.goodness.admit(&original, &expanded) else {
+                self.curves.push(original);
+                return None;
+            };
+            if admitted {
                 return Some(reduced);
             }
             let (head, tail) = original.subdivide(self.time);
@@ -56,7 +67,7 @@ where
 mod tests {
     use crate::approximation::Approximation;
     use crate::bezier::goodness::CrudeIndependentAbsolute;
-    use crate::bezier::{Cubic, Quadratic};
+    use crate::bezier::{Cubic, Linear, Quadratic};
     use crate::expand::Expand;
 
     #[test]
@@ -81,6 +92,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn approximate_3d() {
+        let goodness = CrudeIndependentAbsolute::new(1.0, f64::MAX, usize::MAX);
+        let x = Cubic::new(0.0, 0.0, 90.0, 100.0);
+        let y = Cubic::new(0.0, 50.0, 0.0, 0.0);
+        let z = Cubic::new(0.0, 10.0, 20.0, 30.0);
+        assert_eq!(
+            render(Approximation::new((x, y, z), goodness).map(|(x, y, _)| (x, y))),
+            "M0,0 Q1,18,14,21 Q28,24,46,19 Q65,13,80,7 Q96,1,100,0",
+        );
+    }
+
+    #[test]
+    fn approximate_array() {
+        let goodness = CrudeIndependentAbsolute::new(1.0, f64::MAX, usize::MAX);
+        let x = Cubic::new(0.0, 0.0, 90.0, 100.0);
+        let y = Cubic::new(0.0, 50.0, 0.0, 0.0);
+        assert_eq!(
+            render(Approximation::new([x, y], goodness).map(|[x, y]| (x, y))),
+            "M0,0 Q1,18,14,21 Q28,24,46,19 Q65,13,80,7 Q96,1,100,0",
+        );
+    }
+
     #[test]
     fn exact() {
         let goodness = CrudeIndependentAbsolute::new(0.0, 0.0, usize::MAX);
@@ -92,6 +126,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn linear() {
+        let goodness = CrudeIndependentAbsolute::new(0.0, 0.0, usize::MAX);
+        let x = Linear::new(0.0, 100.0).expand();
+        let y = Linear::new(0.0, 50.0).expand();
+        assert_eq!(
+            render_linear(Approximation::new((x, y), goodness)),
+            "M0,0 L100,50",
+        );
+    }
+
+    #[test]
+    fn polyline() {
+        let goodness = CrudeIndependentAbsolute::new(5.0, f64::MAX, usize::MAX);
+        let x = Quadratic::new(0.0, 50.0, 100.0);
+        let y = Quadratic::new(0.0, 100.0, 0.0);
+        assert_eq!(
+            render_linear(Approximation::new((x, y), goodness)),
+            "M0,0 L12,22 L25,38 L38,47 L50,50 L62,47 L75,38 L88,22 L100,0",
+        );
+    }
+
+    #[test]
+    fn polyline_chained() {
+        let goodness = CrudeIndependentAbsolute::new(1.0, f64::MAX, usize::MAX);
+        let x = Cubic::new(0.0, 0.0, 90.0, 100.0);
+        let y = Cubic::new(0.0, 50.0, 0.0, 0.0);
+        let lines = Approximation::new((x, y), goodness)
+            .flat_map(|quadratic| {
+                let goodness = CrudeIndependentAbsolute::new(1.0, f64::MAX, usize::MAX);
+                Approximation::new(quadratic, goodness)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(lines.first().map(|(x, y)| (x[0], y[0])), Some((0.0, 0.0)));
+        assert_eq!(lines.last().map(|(x, y)| (x[1], y[1])), Some((100.0, 0.0)));
+        for pair in lines.windows(2) {
+            assert_eq!((pair[0].0[1], pair[0].1[1]), (pair[1].0[0], pair[1].1[0]));
+        }
+    }
+
     fn render<T>(curves: T) -> String
     where
         T: Iterator<Item = (Quadratic<f64>, Quadratic<f64>)>,
@@ -102,4 +176,15 @@ mod tests {
             .join(" ");
         format!("M0,0 {curves}")
     }
+
+    fn render_linear<T>(curves: T) -> String
+    where
+        T: Iterator<Item = (Linear<f64>, Linear<f64>)>,
+    {
+        let curves = curves
+            .map(|(x, y)| format!("L{:.0},{:.0}", x[1], y[1]))
+            .collect::<Vec<_>>()
+            .join(" ");
+        format!("M0,0 {curves}")
+    }
 }
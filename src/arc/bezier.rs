@@ -0,0 +1,102 @@
+use num_traits::Float;
+
+use crate::arc::Arc;
+use crate::bezier::{Cubic, Quadratic};
+use crate::transform::Transform;
+
+impl<T: Float> From<Arc<T>> for (Cubic<T>, Cubic<T>) {
+    fn from(arc: Arc<T>) -> Self {
+        let (start, end) = (arc.start, arc.start + arc.sweep);
+        let (sin_start, cos_start) = start.sin_cos();
+        let (sin_end, cos_end) = end.sin_cos();
+        let four = T::from(4).unwrap();
+        let k = four / T::from(3).unwrap() * (arc.sweep / four).tan();
+        let x = Cubic::new(
+            cos_start,
+            cos_start - k * sin_start,
+            cos_end + k * sin_end,
+            cos_end,
+        );
+        let y = Cubic::new(
+            sin_start,
+            sin_start + k * cos_start,
+            sin_end - k * cos_end,
+            sin_end,
+        );
+        (x, y).transform(&arc.transformation())
+    }
+}
+
+impl<T: Float> From<Arc<T>> for (Quadratic<T>, Quadratic<T>) {
+    fn from(arc: Arc<T>) -> Self {
+        let two = T::one() + T::one();
+        let (sin_start, cos_start) = arc.start.sin_cos();
+        let (sin_middle, cos_middle) = (arc.start + arc.sweep / two).sin_cos();
+        let (sin_end, cos_end) = (arc.start + arc.sweep).sin_cos();
+        let k = (arc.sweep / two).cos().recip();
+        let x = Quadratic::new(cos_start, k * cos_middle, cos_end);
+        let y = Quadratic::new(sin_start, k * sin_middle, sin_end);
+        (x, y).transform(&arc.transformation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use crate::arc::goodness::SampledRadialAbsolute;
+    use crate::arc::Arc;
+    use crate::bezier::{Cubic, Quadratic};
+    use crate::conversion::Conversion;
+    use crate::evaluate::Evaluate;
+
+    #[test]
+    fn cubic() {
+        let arc = Arc::new((0.0, 0.0), (100.0, 100.0), 0.0, 0.0, 2.0 * PI);
+        let goodness = SampledRadialAbsolute::new(0.1, f64::MAX, usize::MAX);
+        let curves: Vec<(Cubic<f64>, Cubic<f64>)> = Conversion::new(arc, goodness).collect();
+        assert_eq!(curves.len(), 4);
+        let (x, y) = curves[0];
+        assert::close(x[1], 100.0, 1e-12);
+        assert::close(y[1], 55.22847498307936, 1e-12);
+        assert_close(&curves, arc, 0.03);
+    }
+
+    #[test]
+    fn quadratic() {
+        let arc = Arc::new((10.0, 20.0), (200.0, 100.0), FRAC_PI_2, 0.0, -PI);
+        let goodness = SampledRadialAbsolute::new(0.1, f64::MAX, usize::MAX);
+        let curves: Vec<(Quadratic<f64>, Quadratic<f64>)> =
+            Conversion::new(arc, goodness).collect();
+        assert_eq!(curves.len(), 8);
+        assert_close(&curves, arc, 0.1);
+    }
+
+    #[test]
+    fn incomplete() {
+        let arc = Arc::new((0.0, 0.0), (100.0, 100.0), 0.0, 0.0, 2.0 * PI);
+        let goodness = SampledRadialAbsolute::new(0.0, 0.0, 2);
+        let curves: Vec<(Cubic<f64>, Cubic<f64>)> = Conversion::new(arc, goodness).collect();
+        assert_eq!(curves.len(), 0);
+    }
+
+    fn assert_close<U>(curves: &[U], arc: Arc<f64>, distance: f64)
+    where
+        U: Evaluate<f64, Target = (f64, f64)>,
+    {
+        let (cx, cy) = arc.center();
+        let (rx, ry) = arc.radii();
+        let (sin, cos) = arc.rotation().sin_cos();
+        let (x, y) = curves[0].evaluate(0.0);
+        let (x, y) = (x - arc.evaluate(0.0).0, y - arc.evaluate(0.0).1);
+        assert!(x.hypot(y) < 1e-12);
+        for curve in curves {
+            for i in 0..=10 {
+                let (x, y) = curve.evaluate(i as f64 / 10.0);
+                let (x, y) = (x - cx, y - cy);
+                let (x, y) = ((cos * x + sin * y) / rx, (-sin * x + cos * y) / ry);
+                assert!((x.hypot(y) - 1.0).abs() * rx.max(ry) <= distance);
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use num_traits::Float;
+
+use crate::arc::Arc;
+use crate::evaluate::Evaluate;
+
+impl<T> Evaluate<T> for Arc<T>
+where
+    T: Float,
+{
+    type Target = (T, T);
+
+    fn evaluate(&self, t: T) -> Self::Target {
+        debug_assert!(T::zero() <= t && t <= T::one());
+        let (sin, cos) = (self.start + self.sweep * t).sin_cos();
+        self.transformation().apply((cos, sin))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use crate::arc::Arc;
+    use crate::evaluate::Evaluate;
+
+    #[test]
+    fn evaluate() {
+        let arc = Arc::new((1.0, 2.0), (2.0, 1.0), FRAC_PI_2, 0.0, PI);
+        let points = [(1.0, 4.0), (0.0, 2.0), (1.0, 0.0)];
+        for (t, point) in [0.0, 0.5, 1.0].into_iter().zip(points) {
+            let (x, y) = arc.evaluate(t);
+            assert::close(x, point.0, 1e-15);
+            assert::close(y, point.1, 1e-15);
+        }
+    }
+
+    #[test]
+    fn from_endpoints() {
+        let arc =
+            Arc::from_endpoints((0.0, 0.0), (2.0, 0.0), (1.0, 1.0), 0.0, false, true).unwrap();
+        assert::close(arc.center().0, 1.0, 1e-15);
+        assert::close(arc.center().1, 0.0, 1e-15);
+        assert::close(arc.sweep(), PI, 1e-15);
+        let (x, y) = arc.evaluate(0.5);
+        assert::close(x, 1.0, 1e-15);
+        assert::close(y, -1.0, 1e-15);
+
+        let arc =
+            Arc::from_endpoints((0.0, 0.0), (1.0, 1.0), (1.0, 1.0), 0.0, true, false).unwrap();
+        assert::close(arc.center().0, 0.0, 1e-15);
+        assert::close(arc.center().1, 1.0, 1e-15);
+        assert::close(arc.sweep(), -1.5 * PI, 1e-15);
+
+        let arc =
+            Arc::from_endpoints((0.0, 0.0), (4.0, 0.0), (1.0, 1.0), 0.0, false, false).unwrap();
+        assert_eq!(arc.radii(), (2.0, 2.0));
+
+        assert!(
+            Arc::from_endpoints((1.0, 1.0), (1.0, 1.0), (1.0, 1.0), 0.0, false, false).is_none()
+        );
+        assert!(
+            Arc::from_endpoints((0.0, 0.0), (1.0, 1.0), (0.0, 1.0), 0.0, false, false).is_none()
+        );
+    }
+}
@@ -0,0 +1,68 @@
+//! Goodness of fit.
+
+use num_traits::Float;
+
+use crate::arc::Arc;
+use crate::evaluate::Evaluate;
+use crate::goodness::Goodness;
+
+const SAMPLES: usize = 16;
+
+/// A goodness of fit based on the absolute distance between an arc and points sampled on a
+/// candidate curve, measured along the rays going out of the center of the underlying circle.
+pub struct SampledRadialAbsolute<T: Float> {
+    absolute_distance: T,
+    relative_distance: T,
+    subdivision: usize,
+    index: usize,
+}
+
+impl<T: Float> SampledRadialAbsolute<T> {
+    /// Create an instance.
+    ///
+    /// * `absolute_distance` dictates the maximum distance between the points of a candidate
+    ///   curve and the arc.
+    ///
+    /// * `relative_distance` dictates the maximum distance between the points of a candidate
+    ///   curve and the arc relative to the larger radius.
+    ///
+    /// * `subdivision` dictates the maximum number of subdivisions that can be made during a
+    ///   conversion process.
+    #[inline]
+    pub fn new(absolute_distance: T, relative_distance: T, subdivision: usize) -> Self {
+        Self {
+            absolute_distance,
+            relative_distance,
+            subdivision,
+            index: 0,
+        }
+    }
+}
+
+impl<T, U> Goodness<(Arc<T>, U)> for SampledRadialAbsolute<T>
+where
+    T: Float,
+    U: Evaluate<T, Target = (T, T)>,
+{
+    fn admit(&mut self, candidate: &(Arc<T>, U), original: &(Arc<T>, U)) -> Option<bool> {
+        let (arc, curve) = candidate;
+        let size = original.0.radii.0.max(original.0.radii.1);
+        let distance = self.absolute_distance.min(self.relative_distance * size);
+        let (sin, cos) = arc.rotation.sin_cos();
+        let admitted = (0..=SAMPLES).all(|i| {
+            let (x, y) = curve.evaluate(T::from(i).unwrap() / T::from(SAMPLES).unwrap());
+            let (x, y) = (x - arc.center.0, y - arc.center.1);
+            let (x, y) = (cos * x + sin * y, -sin * x + cos * y);
+            let norm = (x / arc.radii.0).hypot(y / arc.radii.1);
+            (x - x / norm).hypot(y - y / norm) <= distance
+        });
+        if admitted {
+            return Some(true);
+        }
+        if self.index < self.subdivision {
+            self.index += 1;
+            return Some(false);
+        }
+        None
+    }
+}
@@ -0,0 +1,129 @@
+//! Elliptical arcs.
+
+pub mod goodness;
+
+mod bezier;
+mod evaluate;
+mod subdivide;
+
+use num_traits::Float;
+
+use crate::transform::Affine;
+
+/// An elliptical arc.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Arc<T> {
+    center: (T, T),
+    radii: (T, T),
+    rotation: T,
+    start: T,
+    sweep: T,
+}
+
+impl<T: Float> Arc<T> {
+    /// Create an instance.
+    ///
+    /// The arc goes around `center` with `radii` along the axes of the ellipse, which are rotated
+    /// by `rotation` radians, starting at angle `start` and covering angle `sweep`, which is
+    /// positive for the counterclockwise direction.
+    #[inline]
+    pub fn new(center: (T, T), radii: (T, T), rotation: T, start: T, sweep: T) -> Self {
+        Self {
+            center,
+            radii,
+            rotation,
+            start,
+            sweep,
+        }
+    }
+
+    /// Create an instance from endpoints as in SVG.
+    ///
+    /// The radii are scaled up if they are too small to connect the endpoints, and the rotation
+    /// is in radians. The flag `large` chooses the arc that is larger than a half ellipse, and
+    /// the flag `sweep` chooses the arc that goes in the positive-angle direction. No instance
+    /// is created when the endpoints coincide or either radius is zero, in which case SVG
+    /// prescribes either omitting the arc or drawing a straight line.
+    pub fn from_endpoints(
+        from: (T, T),
+        to: (T, T),
+        radii: (T, T),
+        rotation: T,
+        large: bool,
+        sweep: bool,
+    ) -> Option<Self> {
+        // Reference:
+        // https://www.w3.org/TR/SVG11/implnote.html#ArcConversionEndpointToCenter
+        let (mut rx, mut ry) = (radii.0.abs(), radii.1.abs());
+        if from == to || rx.is_zero() || ry.is_zero() {
+            return None;
+        }
+        let two = T::one() + T::one();
+        let (sin, cos) = rotation.sin_cos();
+        let (dx, dy) = ((from.0 - to.0) / two, (from.1 - to.1) / two);
+        let (x, y) = (cos * dx + sin * dy, -sin * dx + cos * dy);
+        let lambda = (x / rx).powi(2) + (y / ry).powi(2);
+        if lambda > T::one() {
+            rx = rx * lambda.sqrt();
+            ry = ry * lambda.sqrt();
+        }
+        let numerator = (rx * ry).powi(2) - (rx * y).powi(2) - (ry * x).powi(2);
+        let denominator = (rx * y).powi(2) + (ry * x).powi(2);
+        let mut coefficient = (numerator / denominator).max(T::zero()).sqrt();
+        if large == sweep {
+            coefficient = -coefficient;
+        }
+        let (cx, cy) = (coefficient * rx * y / ry, -coefficient * ry * x / rx);
+        let center = (
+            cos * cx - sin * cy + (from.0 + to.0) / two,
+            sin * cx + cos * cy + (from.1 + to.1) / two,
+        );
+        let start = ((y - cy) / ry).atan2((x - cx) / rx);
+        let end = ((-y - cy) / ry).atan2((-x - cx) / rx);
+        let full = T::from(std::f64::consts::TAU).unwrap();
+        let mut delta = (end - start) % full;
+        if !sweep && delta > T::zero() {
+            delta = delta - full;
+        } else if sweep && delta < T::zero() {
+            delta = delta + full;
+        }
+        Some(Self::new(center, (rx, ry), rotation, start, delta))
+    }
+
+    /// Return the center.
+    #[inline]
+    pub fn center(&self) -> (T, T) {
+        self.center
+    }
+
+    /// Return the radii.
+    #[inline]
+    pub fn radii(&self) -> (T, T) {
+        self.radii
+    }
+
+    /// Return the rotation.
+    #[inline]
+    pub fn rotation(&self) -> T {
+        self.rotation
+    }
+
+    /// Return the start angle.
+    #[inline]
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// Return the sweep angle.
+    #[inline]
+    pub fn sweep(&self) -> T {
+        self.sweep
+    }
+
+    /// Return the transformation that maps the unit circle onto the ellipse.
+    fn transformation(&self) -> Affine<T> {
+        Affine::scale(self.radii.0, self.radii.1)
+            .then(&Affine::rotate(self.rotation))
+            .then(&Affine::translate(self.center.0, self.center.1))
+    }
+}
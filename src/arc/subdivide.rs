@@ -0,0 +1,21 @@
+use num_traits::Float;
+
+use crate::arc::Arc;
+use crate::subdivide::Subdivide;
+
+impl<T> Subdivide<T> for Arc<T>
+where
+    T: Float,
+{
+    fn subdivide(&self, t: T) -> (Self, Self) {
+        debug_assert!(T::zero() <= t && t <= T::one());
+        let sweep = self.sweep * t;
+        let head = Self { sweep, ..*self };
+        let tail = Self {
+            start: self.start + sweep,
+            sweep: self.sweep - sweep,
+            ..*self
+        };
+        (head, tail)
+    }
+}
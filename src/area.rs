@@ -0,0 +1,43 @@
+use num_traits::Float;
+
+/// A direction of travel around a contour.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    /// The direction in which the signed area is negative.
+    Clockwise,
+    /// The direction in which the signed area is positive.
+    Counterclockwise,
+}
+
+/// A shape with a signed area.
+///
+/// For a curve, the area is the integral of `(x dy - y dx) / 2` along it, which sums up to the
+/// enclosed area for closed sequences of curves. The area is positive for counterclockwise
+/// contours when the y-axis points up, and the other way around when it points down.
+pub trait Area<T: Float> {
+    /// Perform the calculation.
+    fn area(&self) -> T;
+
+    /// Determine the direction, which is absent if the area is zero.
+    fn direction(&self) -> Option<Direction> {
+        let area = self.area();
+        if area > T::zero() {
+            Some(Direction::Counterclockwise)
+        } else if area < T::zero() {
+            Some(Direction::Clockwise)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, U> Area<T> for [U]
+where
+    T: Float,
+    U: Area<T>,
+{
+    fn area(&self) -> T {
+        self.iter()
+            .fold(T::zero(), |area, curve| area + curve.area())
+    }
+}
@@ -1,7 +1,23 @@
 use num_traits::Float;
 
 use crate::align::Align;
-use crate::bezier::{Cubic, Quadratic};
+use crate::bezier::{Cubic, Linear, Quadratic};
+
+impl<T: Float> Align<T, Quadratic<T>> for Linear<T> {
+    fn align(mut self, other: &Quadratic<T>) -> Self {
+        self[0] = other[0];
+        self[1] = other[2];
+        self
+    }
+}
+
+impl<T: Float> Align<T, Cubic<T>> for Linear<T> {
+    fn align(mut self, other: &Cubic<T>) -> Self {
+        self[0] = other[0];
+        self[1] = other[3];
+        self
+    }
+}
 
 impl<T: Float> Align<T, Cubic<T>> for Quadratic<T> {
     fn align(mut self, other: &Cubic<T>) -> Self {
@@ -0,0 +1,114 @@
+// Reference:
+// https://en.wikipedia.org/wiki/Green%27s_theorem#Area_calculation
+
+use num_traits::Float;
+
+use crate::area::Area;
+use crate::bezier::{Cubic, Linear, Quadratic};
+
+impl<T: Float> Area<T> for (Linear<T>, Linear<T>) {
+    fn area(&self) -> T {
+        let (x, y) = (&self.0[..], &self.1[..]);
+        cross(x, y, 0, 1) / T::from(2).unwrap()
+    }
+}
+
+impl<T: Float> Area<T> for (Quadratic<T>, Quadratic<T>) {
+    fn area(&self) -> T {
+        let (x, y) = (&self.0[..], &self.1[..]);
+        let two = T::from(2).unwrap();
+        (two * cross(x, y, 0, 1) + two * cross(x, y, 1, 2) + cross(x, y, 0, 2))
+            / T::from(6).unwrap()
+    }
+}
+
+impl<T: Float> Area<T> for (Cubic<T>, Cubic<T>) {
+    fn area(&self) -> T {
+        let (x, y) = (&self.0[..], &self.1[..]);
+        let (three, six) = (T::from(3).unwrap(), T::from(6).unwrap());
+        (six * cross(x, y, 0, 1)
+            + three * cross(x, y, 0, 2)
+            + cross(x, y, 0, 3)
+            + three * cross(x, y, 1, 2)
+            + three * cross(x, y, 1, 3)
+            + six * cross(x, y, 2, 3))
+            / T::from(20).unwrap()
+    }
+}
+
+#[inline]
+fn cross<T: Float>(x: &[T], y: &[T], i: usize, j: usize) -> T {
+    x[i] * y[j] - x[j] * y[i]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::arc::Arc;
+    use crate::area::{Area, Direction};
+    use crate::bezier::{Cubic, Linear, Quadratic};
+    use crate::derive::Derive;
+    use crate::evaluate::Evaluate;
+
+    #[test]
+    fn circle() {
+        let curves: Vec<(Cubic<f64>, Cubic<f64>)> = (0..4)
+            .map(|i| {
+                let start = i as f64 * std::f64::consts::FRAC_PI_2;
+                Arc::new(
+                    (1.0, 2.0),
+                    (3.0, 3.0),
+                    0.0,
+                    start,
+                    std::f64::consts::FRAC_PI_2,
+                )
+                .into()
+            })
+            .collect();
+        assert::close(curves.area(), 9.0 * std::f64::consts::PI, 0.1);
+        assert_eq!(curves.direction(), Some(Direction::Counterclockwise));
+    }
+
+    #[test]
+    fn integral() {
+        let curve = (
+            Cubic::new(1.0, 0.0, 90.0, 100.0),
+            Cubic::new(2.0, 50.0, -7.0, 3.0),
+        );
+        assert::close(curve.area(), integrate(&curve), 1e-3);
+        let curve = (
+            Quadratic::new(1.0, 5.0, -3.0),
+            Quadratic::new(-2.0, 4.0, 7.0),
+        );
+        assert::close(curve.area(), integrate(&curve), 1e-3);
+    }
+
+    #[test]
+    fn triangle() {
+        let curves = [
+            (Linear::new(0.0, 4.0), Linear::new(0.0, 0.0)),
+            (Linear::new(4.0, 0.0), Linear::new(0.0, 3.0)),
+            (Linear::new(0.0, 0.0), Linear::new(3.0, 0.0)),
+        ];
+        assert_eq!(curves.area(), 6.0);
+        let curves = [curves[2], curves[1], curves[0]]
+            .map(|(x, y)| (Linear::new(x[1], x[0]), Linear::new(y[1], y[0])));
+        assert_eq!(curves.direction(), Some(Direction::Clockwise));
+    }
+
+    fn integrate<U>(curve: &(U, U)) -> f64
+    where
+        U: Derive<f64> + Evaluate<f64, Target = f64>,
+        <U as Derive<f64>>::Target: Evaluate<f64, Target = f64>,
+    {
+        let derivative = curve.derive();
+        let count = 10000;
+        (0..count)
+            .map(|i| {
+                let t = (i as f64 + 0.5) / count as f64;
+                let (x, y) = curve.evaluate(t);
+                let (u, v) = derivative.evaluate(t);
+                (x * v - y * u) / 2.0 / count as f64
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,217 @@
+//! Batched evaluation.
+//!
+//! The curves are stored in the structure-of-arrays layout, and the control points are processed
+//! in fixed-size chunks free of branches, which lets the compiler vectorize the loops.
+
+use num_traits::Float;
+
+use crate::bezier::Cubic;
+
+const LANES: usize = 8;
+
+/// A collection of cubic curves in the structure-of-arrays layout.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Cubics<T> {
+    beta: [Vec<T>; 4],
+}
+
+impl<T: Float> Cubics<T> {
+    /// Create an instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            beta: [vec![], vec![], vec![], vec![]],
+        }
+    }
+
+    /// Add a curve.
+    #[inline]
+    pub fn push(&mut self, curve: Cubic<T>) {
+        for (beta, value) in self.beta.iter_mut().zip(curve.0) {
+            beta.push(value);
+        }
+    }
+
+    /// Return a curve.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Cubic<T>> {
+        if index >= self.len() {
+            return None;
+        }
+        Some(Cubic(self.beta.each_ref().map(|beta| beta[index])))
+    }
+
+    /// Return the number of curves.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.beta[0].len()
+    }
+
+    /// Check if there are no curves.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.beta[0].is_empty()
+    }
+
+    /// Evaluate all curves at the same point and write the results into a slice.
+    ///
+    /// The slice is expected to have the same length as the collection.
+    pub fn evaluate(&self, t: T, values: &mut [T]) {
+        assert_eq!(values.len(), self.len());
+        debug_assert!(T::zero() <= t && t <= T::one());
+        let [b0, b1, b2, b3] = self.beta.each_ref().map(|beta| &beta[..]);
+        let mut values = values.chunks_exact_mut(LANES);
+        let mut b0 = b0.chunks_exact(LANES);
+        let mut b1 = b1.chunks_exact(LANES);
+        let mut b2 = b2.chunks_exact(LANES);
+        let mut b3 = b3.chunks_exact(LANES);
+        for values in values.by_ref() {
+            let (b0, b1, b2, b3) = (
+                b0.next().unwrap(),
+                b1.next().unwrap(),
+                b2.next().unwrap(),
+                b3.next().unwrap(),
+            );
+            for i in 0..LANES {
+                values[i] = casteljau([b0[i], b1[i], b2[i], b3[i]], t);
+            }
+        }
+        let (b0, b1, b2, b3) = (
+            b0.remainder(),
+            b1.remainder(),
+            b2.remainder(),
+            b3.remainder(),
+        );
+        for (i, value) in values.into_remainder().iter_mut().enumerate() {
+            *value = casteljau([b0[i], b1[i], b2[i], b3[i]], t);
+        }
+    }
+
+    /// Evaluate each curve at its own point and write the results into a slice.
+    ///
+    /// The points and the slice are expected to have the same length as the collection.
+    pub fn evaluate_each(&self, t: &[T], values: &mut [T]) {
+        assert_eq!(t.len(), self.len());
+        assert_eq!(values.len(), self.len());
+        let [b0, b1, b2, b3] = self.beta.each_ref().map(|beta| &beta[..]);
+        let mut values = values.chunks_exact_mut(LANES);
+        let mut t = t.chunks_exact(LANES);
+        let mut b0 = b0.chunks_exact(LANES);
+        let mut b1 = b1.chunks_exact(LANES);
+        let mut b2 = b2.chunks_exact(LANES);
+        let mut b3 = b3.chunks_exact(LANES);
+        for values in values.by_ref() {
+            let (t, b0, b1, b2, b3) = (
+                t.next().unwrap(),
+                b0.next().unwrap(),
+                b1.next().unwrap(),
+                b2.next().unwrap(),
+                b3.next().unwrap(),
+            );
+            for i in 0..LANES {
+                values[i] = casteljau([b0[i], b1[i], b2[i], b3[i]], t[i]);
+            }
+        }
+        let (t, b0, b1, b2, b3) = (
+            t.remainder(),
+            b0.remainder(),
+            b1.remainder(),
+            b2.remainder(),
+            b3.remainder(),
+        );
+        for (i, value) in values.into_remainder().iter_mut().enumerate() {
+            *value = casteljau([b0[i], b1[i], b2[i], b3[i]], t[i]);
+        }
+    }
+}
+
+impl<T: Float> Cubic<T> {
+    /// Evaluate at many points and write the results into a slice.
+    ///
+    /// The slice is expected to have the same length as the points.
+    pub fn evaluate_many(&self, t: &[T], values: &mut [T]) {
+        assert_eq!(values.len(), t.len());
+        let mut values = values.chunks_exact_mut(LANES);
+        let mut t = t.chunks_exact(LANES);
+        for values in values.by_ref() {
+            let t = t.next().unwrap();
+            for i in 0..LANES {
+                values[i] = casteljau(self.0, t[i]);
+            }
+        }
+        let t = t.remainder();
+        for (i, value) in values.into_remainder().iter_mut().enumerate() {
+            *value = casteljau(self.0, t[i]);
+        }
+    }
+}
+
+impl<T: Float> FromIterator<Cubic<T>> for Cubics<T> {
+    fn from_iter<I: IntoIterator<Item = Cubic<T>>>(iterator: I) -> Self {
+        let mut cubics = Self::new();
+        for curve in iterator {
+            cubics.push(curve);
+        }
+        cubics
+    }
+}
+
+// The steps are the same as in the evaluation of a single curve so that the results are identical.
+#[inline(always)]
+fn casteljau<T: Float>(mut beta: [T; 4], t: T) -> T {
+    let u = T::one() - t;
+    beta[0] = beta[0] * u + beta[1] * t;
+    beta[1] = beta[1] * u + beta[2] * t;
+    beta[2] = beta[2] * u + beta[3] * t;
+    beta[0] = beta[0] * u + beta[1] * t;
+    beta[1] = beta[1] * u + beta[2] * t;
+    beta[0] * u + beta[1] * t
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bezier::batch::Cubics;
+    use crate::bezier::Cubic;
+    use crate::evaluate::Evaluate;
+
+    #[test]
+    fn evaluate() {
+        let curves = (0..19)
+            .map(|i| {
+                let i = i as f64;
+                Cubic::new(i, 2.0 * i - 1.0, 0.5 * i * i, 3.0 - i)
+            })
+            .collect::<Vec<_>>();
+        let cubics = curves.iter().copied().collect::<Cubics<_>>();
+        assert_eq!(cubics.len(), 19);
+        assert_eq!(cubics.get(7), Some(curves[7]));
+        assert_eq!(cubics.get(19), None);
+        let mut values = vec![0.0; 19];
+        for t in [0.0, 0.3, 0.75, 1.0] {
+            cubics.evaluate(t, &mut values);
+            for (curve, value) in curves.iter().zip(&values) {
+                assert_eq!(*value, curve.evaluate(t));
+            }
+        }
+        let t = (0..19).map(|i| i as f64 / 18.0).collect::<Vec<_>>();
+        cubics.evaluate_each(&t, &mut values);
+        for ((curve, t), value) in curves.iter().zip(&t).zip(&values) {
+            assert_eq!(*value, curve.evaluate(*t));
+        }
+    }
+
+    #[test]
+    fn evaluate_many() {
+        let curve = (
+            Cubic::new(0.0, 0.0, 90.0, 100.0),
+            Cubic::new(0.0, 50.0, 0.0, 0.0),
+        );
+        let t = (0..21).map(|i| i as f64 / 20.0).collect::<Vec<_>>();
+        let (mut x, mut y) = (vec![0.0; 21], vec![0.0; 21]);
+        curve.0.evaluate_many(&t, &mut x);
+        curve.1.evaluate_many(&t, &mut y);
+        for (i, t) in t.into_iter().enumerate() {
+            assert_eq!((x[i], y[i]), curve.evaluate(t));
+        }
+    }
+}
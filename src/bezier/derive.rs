@@ -0,0 +1,52 @@
+// Reference:
+// https://pomax.github.io/bezierinfo/#derivatives
+
+use num_traits::Float;
+
+use crate::bezier::{Cubic, Linear, Quadratic};
+use crate::derive::Derive;
+
+impl<T: Float> Derive<T> for Linear<T> {
+    type Target = Linear<T>;
+
+    fn derive(&self) -> Self::Target {
+        let value = self[1] - self[0];
+        Linear::new(value, value)
+    }
+}
+
+impl<T: Float> Derive<T> for Quadratic<T> {
+    type Target = Linear<T>;
+
+    fn derive(&self) -> Self::Target {
+        let two = T::one() + T::one();
+        Linear::new(two * (self[1] - self[0]), two * (self[2] - self[1]))
+    }
+}
+
+impl<T: Float> Derive<T> for Cubic<T> {
+    type Target = Quadratic<T>;
+
+    fn derive(&self) -> Self::Target {
+        let three = T::one() + T::one() + T::one();
+        Quadratic::new(
+            three * (self[1] - self[0]),
+            three * (self[2] - self[1]),
+            three * (self[3] - self[2]),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bezier::{Cubic, Linear, Quadratic};
+    use crate::derive::Derive;
+
+    #[test]
+    fn derive() {
+        let x = Cubic::new(1.0, 3.0, 5.0, 6.0);
+        assert_eq!(x.derive(), Quadratic::new(6.0, 6.0, 3.0));
+        assert_eq!(x.derive().derive(), Linear::new(0.0, -6.0));
+        assert_eq!(x.derive().derive().derive(), Linear::new(-6.0, -6.0));
+    }
+}
@@ -0,0 +1,23 @@
+use num_traits::Float;
+
+use crate::bezier::{Cubic, Linear, Quadratic};
+use crate::difference::{Difference, Differences};
+
+macro_rules! implement {
+    ($($type:ident => $count:expr),*) => ($(
+        impl<T: Float> Difference<T> for $type<T> {
+            type State = Differences<T, $count>;
+
+            fn start(&self, step: T) -> Self::State {
+                Differences::new(self.polynomial(), step)
+            }
+
+            #[inline]
+            fn advance(state: &mut Self::State) -> Self::Target {
+                state.advance()
+            }
+        }
+    )*);
+}
+
+implement!(Linear => 2, Quadratic => 3, Cubic => 4);
@@ -3,7 +3,7 @@
 
 use num_traits::Float;
 
-use crate::bezier::{Cubic, Linear, Quadratic};
+use crate::bezier::{Cubic, Linear, Quadratic, RationalCubic, RationalQuadratic};
 use crate::evaluate::Evaluate;
 
 impl<T> Evaluate<T> for Linear<T>
@@ -54,3 +54,22 @@ where
         beta[0] * u + beta[1] * t
     }
 }
+
+macro_rules! implement {
+    ($($type:ident),*) => ($(
+        impl<T> Evaluate<T> for $type<T>
+        where
+            T: Float,
+        {
+            type Target = (T, T);
+
+            fn evaluate(&self, t: T) -> Self::Target {
+                let (x, y, weights) = self.homogenize();
+                let weight = weights.evaluate(t);
+                (x.evaluate(t) / weight, y.evaluate(t) / weight)
+            }
+        }
+    )*);
+}
+
+implement!(RationalQuadratic, RationalCubic);
@@ -1,11 +1,19 @@
 // Reference:
 // https://pomax.github.io/bezierinfo/#reordering
 
-use crate::bezier::{Cubic, Quadratic};
+use crate::bezier::{Cubic, Linear, Quadratic};
 use crate::expand::Expand;
 
 macro_rules! implement {
     ($($type:ty),*) => ($(
+        impl Expand<$type> for Linear<$type> {
+            type Target = Quadratic<$type>;
+
+            fn expand(&self) -> Self::Target {
+                Self::Target::new(self[0], (self[0] + self[1]) / 2.0, self[1])
+            }
+        }
+
         impl Expand<$type> for Quadratic<$type> {
             type Target = Cubic<$type>;
 
@@ -25,7 +33,7 @@ implement!(f32, f64);
 
 #[cfg(test)]
 mod tests {
-    use crate::bezier::{Cubic, Quadratic};
+    use crate::bezier::{Cubic, Linear, Quadratic};
     use crate::expand::Expand;
     use crate::reduce::Reduce;
 
@@ -38,4 +46,12 @@ mod tests {
         assert_eq!(x.reduce(), Quadratic::new(-10.0, 50.0, 110.0));
         assert_eq!(y.reduce(), Quadratic::new(0.0, 150.0, 0.0));
     }
+
+    #[test]
+    fn expand_linear() {
+        let x: Quadratic<_> = Linear::new(-10.0, 110.0).expand();
+        assert_eq!(x, Quadratic::new(-10.0, 50.0, 110.0));
+        assert_eq!(x.reduce(), Linear::new(-10.0, 110.0));
+        assert_eq!(x.expand(), Cubic::new(-10.0, 30.0, 70.0, 110.0));
+    }
 }
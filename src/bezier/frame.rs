@@ -0,0 +1,204 @@
+use num_traits::Float;
+
+use crate::bezier::{Cubic, Linear, Quadratic};
+use crate::derive::Derive;
+use crate::evaluate::Evaluate;
+use crate::frame::{Frame, Frenet};
+
+macro_rules! implement {
+    ($($type:ident),*) => ($(
+        impl<T: Float> Frame<T> for ($type<T>, $type<T>) {
+            type Vector = (T, T);
+
+            // At cusps, the direction is taken from the higher derivatives.
+            fn tangent(&self, t: T) -> Self::Vector {
+                let first = self.derive();
+                let second = first.derive();
+                let third = second.derive();
+                let sign = if t < T::from(0.5).unwrap() { T::one() } else { -T::one() };
+                let (x, y) = second.evaluate(t);
+                for (x, y) in [first.evaluate(t), (sign * x, sign * y), third.evaluate(t)] {
+                    let norm = x.hypot(y);
+                    if norm > T::zero() {
+                        return (x / norm, y / norm);
+                    }
+                }
+                (T::one(), T::zero())
+            }
+
+            #[inline]
+            fn normal(&self, t: T) -> Self::Vector {
+                let (x, y) = self.tangent(t);
+                (-y, x)
+            }
+
+            fn curvature(&self, t: T) -> T {
+                let first = self.derive();
+                let (x, y) = first.evaluate(t);
+                let (u, v) = first.derive().evaluate(t);
+                (x * v - y * u) / x.hypot(y).powi(3)
+            }
+        }
+
+        impl<T: Float> Frame<T> for ($type<T>, $type<T>, $type<T>) {
+            type Vector = (T, T, T);
+
+            // At cusps, the direction is taken from the higher derivatives.
+            fn tangent(&self, t: T) -> Self::Vector {
+                let first = self.derive();
+                let second = first.derive();
+                let third = second.derive();
+                let sign = if t < T::from(0.5).unwrap() { T::one() } else { -T::one() };
+                let (x, y, z) = second.evaluate(t);
+                for vector in [first.evaluate(t), (sign * x, sign * y, sign * z), third.evaluate(t)] {
+                    if let Some(vector) = normalize(vector) {
+                        return vector;
+                    }
+                }
+                (T::one(), T::zero(), T::zero())
+            }
+
+            // For straight parts, an arbitrary perpendicular direction is chosen.
+            fn normal(&self, t: T) -> Self::Vector {
+                let tangent = self.tangent(t);
+                let binormal = self.binormal(t);
+                if binormal != (T::zero(), T::zero(), T::zero()) {
+                    return cross(binormal, tangent);
+                }
+                let (x, y, z) = tangent;
+                let axis = if x.abs() <= y.abs() && x.abs() <= z.abs() {
+                    (T::one(), T::zero(), T::zero())
+                } else if y.abs() <= z.abs() {
+                    (T::zero(), T::one(), T::zero())
+                } else {
+                    (T::zero(), T::zero(), T::one())
+                };
+                normalize(cross(tangent, axis)).unwrap()
+            }
+
+            fn curvature(&self, t: T) -> T {
+                let first = self.derive();
+                let velocity = first.evaluate(t);
+                let acceleration = first.derive().evaluate(t);
+                norm(cross(velocity, acceleration)) / norm(velocity).powi(3)
+            }
+        }
+
+        impl<T: Float> Frenet<T> for ($type<T>, $type<T>, $type<T>) {
+            // For straight parts, the binormal is zero.
+            fn binormal(&self, t: T) -> (T, T, T) {
+                let first = self.derive();
+                let vector = cross(first.evaluate(t), first.derive().evaluate(t));
+                normalize(vector).unwrap_or((T::zero(), T::zero(), T::zero()))
+            }
+
+            fn torsion(&self, t: T) -> T {
+                let first = self.derive();
+                let second = first.derive();
+                let vector = cross(first.evaluate(t), second.evaluate(t));
+                let denominator = dot(vector, vector);
+                if denominator.is_zero() {
+                    return T::zero();
+                }
+                dot(vector, second.derive().evaluate(t)) / denominator
+            }
+        }
+    )*);
+}
+
+implement!(Linear, Quadratic, Cubic);
+
+fn cross<T: Float>(one: (T, T, T), other: (T, T, T)) -> (T, T, T) {
+    (
+        one.1 * other.2 - one.2 * other.1,
+        one.2 * other.0 - one.0 * other.2,
+        one.0 * other.1 - one.1 * other.0,
+    )
+}
+
+fn dot<T: Float>(one: (T, T, T), other: (T, T, T)) -> T {
+    one.0 * other.0 + one.1 * other.1 + one.2 * other.2
+}
+
+fn norm<T: Float>(vector: (T, T, T)) -> T {
+    vector.0.hypot(vector.1).hypot(vector.2)
+}
+
+fn normalize<T: Float>(vector: (T, T, T)) -> Option<(T, T, T)> {
+    let norm = norm(vector);
+    if norm > T::zero() {
+        Some((vector.0 / norm, vector.1 / norm, vector.2 / norm))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::arc::Arc;
+    use crate::bezier::{Cubic, Linear, Quadratic};
+    use crate::frame::{Frame, Frenet};
+
+    #[test]
+    fn circle() {
+        let curve = <(Cubic<f64>, Cubic<f64>)>::from(Arc::new(
+            (0.0, 0.0),
+            (2.0, 2.0),
+            0.0,
+            0.0,
+            std::f64::consts::FRAC_PI_2,
+        ));
+        let (x, y) = curve.tangent(0.0);
+        assert::close(&[x, y][..], &[0.0, 1.0][..], 1e-12);
+        let (x, y) = curve.normal(0.0);
+        assert::close(&[x, y][..], &[-1.0, 0.0][..], 1e-12);
+        assert::close(curve.curvature(0.5), 0.5, 1e-2);
+        assert::close(curve.radius(0.5), 2.0, 2e-2);
+    }
+
+    #[test]
+    fn cusp() {
+        let curve = (
+            Cubic::new(0.0, 1.0, 0.0, 1.0),
+            Cubic::new(0.0, 1.0, 1.0, 0.0),
+        );
+        let (x, y): (f64, f64) = curve.tangent(0.5);
+        assert::close(x.hypot(y), 1.0, 1e-12);
+        let curve = (Quadratic::new(0.0, 0.0, 1.0), Quadratic::new(0.0, 0.0, 0.0));
+        assert_eq!(curve.tangent(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn line() {
+        let curve = (Linear::new(0.0, 3.0), Linear::new(0.0, 4.0));
+        assert_eq!(curve.tangent(0.5), (0.6, 0.8));
+        assert_eq!(curve.curvature(0.5), 0.0);
+        assert_eq!(curve.radius(0.5), f64::INFINITY);
+
+        let curve = (
+            Linear::new(0.0, 2.0),
+            Linear::new(0.0, 0.0),
+            Linear::new(0.0, 0.0),
+        );
+        assert_eq!(curve.binormal(0.5), (0.0, 0.0, 0.0));
+        assert_eq!(curve.normal(0.5), (0.0, 0.0, 1.0));
+        assert_eq!(curve.torsion(0.5), 0.0);
+    }
+
+    #[test]
+    fn space() {
+        // The twisted cubic (t, t^2, t^3) in the Bernstein form.
+        let curve = (
+            Cubic::new(0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0),
+            Cubic::new(0.0, 0.0, 1.0 / 3.0, 1.0),
+            Cubic::new(0.0, 0.0, 0.0, 1.0),
+        );
+        // For (t, t^2, t^3) at t = 0, the curvature is 2 and the torsion is 3.
+        assert::close(curve.curvature(0.0), 2.0, 1e-12);
+        assert::close(curve.torsion(0.0), 3.0, 1e-12);
+        let [tangent, normal, binormal] = curve.frame(0.0);
+        assert_eq!(tangent, (1.0, 0.0, 0.0));
+        assert_eq!(normal, (0.0, 1.0, 0.0));
+        assert_eq!(binormal, (0.0, 0.0, 1.0));
+    }
+}
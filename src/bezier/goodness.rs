@@ -2,8 +2,9 @@
 
 use num_traits::Float;
 
-use crate::bezier::Cubic;
+use crate::bezier::{Cubic, Quadratic};
 use crate::goodness::Goodness;
+use crate::point::Point;
 
 /// A goodness of fit based on the coordinate-wise absolute distance between control points.
 pub struct CrudeIndependentAbsolute<T: Float> {
@@ -34,13 +35,18 @@ impl<T: Float> CrudeIndependentAbsolute<T> {
             index: 0,
         }
     }
-}
 
-impl<T: Float> Goodness<Cubic<T>> for CrudeIndependentAbsolute<T> {
-    fn admit(&mut self, candidate: &Cubic<T>, original: &Cubic<T>) -> Option<bool> {
-        let size = (original.0[0] - original.0[3]).abs();
+    fn check(&mut self, candidate: &[&[T]], original: &[&[T]]) -> Option<bool> {
+        let size = original
+            .iter()
+            .map(|curve| (curve[0] - curve[curve.len() - 1]).abs())
+            .fold(T::zero(), T::max);
         let distance = self.absolute_distance.min(self.relative_distance * size);
-        if admit(candidate.0, original.0, distance) {
+        if candidate
+            .iter()
+            .zip(original)
+            .all(|(one, other)| admit(one, other, distance))
+        {
             return Some(true);
         }
         if self.index < self.subdivision {
@@ -51,35 +57,67 @@ impl<T: Float> Goodness<Cubic<T>> for CrudeIndependentAbsolute<T> {
     }
 }
 
-impl<T: Float> Goodness<(Cubic<T>, Cubic<T>)> for CrudeIndependentAbsolute<T> {
-    fn admit(
-        &mut self,
-        candidate: &(Cubic<T>, Cubic<T>),
-        original: &(Cubic<T>, Cubic<T>),
-    ) -> Option<bool> {
-        let size = {
-            let width = (original.0[0] - original.0[3]).abs();
-            let height = (original.1[0] - original.1[3]).abs();
-            width.max(height)
-        };
-        let distance = self.absolute_distance.min(self.relative_distance * size);
-        if admit(candidate.0 .0, original.0 .0, distance)
-            && admit(candidate.1 .0, original.1 .0, distance)
-        {
-            return Some(true);
+macro_rules! implement {
+    ($($type:ident),*) => ($(
+        impl<T: Float> Goodness<$type<T>> for CrudeIndependentAbsolute<T> {
+            fn admit(&mut self, candidate: &$type<T>, original: &$type<T>) -> Option<bool> {
+                self.check(&[&candidate[..]], &[&original[..]])
+            }
         }
-        if self.index < self.subdivision {
-            self.index += 1;
-            return Some(false);
+
+        impl<T: Float> Goodness<($type<T>, $type<T>)> for CrudeIndependentAbsolute<T> {
+            fn admit(
+                &mut self,
+                candidate: &($type<T>, $type<T>),
+                original: &($type<T>, $type<T>),
+            ) -> Option<bool> {
+                self.check(
+                    &[&candidate.0[..], &candidate.1[..]],
+                    &[&original.0[..], &original.1[..]],
+                )
+            }
         }
-        None
-    }
+
+        impl<T: Float> Goodness<($type<T>, $type<T>, $type<T>)> for CrudeIndependentAbsolute<T> {
+            fn admit(
+                &mut self,
+                candidate: &($type<T>, $type<T>, $type<T>),
+                original: &($type<T>, $type<T>, $type<T>),
+            ) -> Option<bool> {
+                self.check(
+                    &[&candidate.0[..], &candidate.1[..], &candidate.2[..]],
+                    &[&original.0[..], &original.1[..], &original.2[..]],
+                )
+            }
+        }
+
+        impl<T: Float> Goodness<$type<Point<T>>> for CrudeIndependentAbsolute<T> {
+            fn admit(
+                &mut self,
+                candidate: &$type<Point<T>>,
+                original: &$type<Point<T>>,
+            ) -> Option<bool> {
+                let candidate = <($type<T>, $type<T>)>::from(*candidate);
+                let original = <($type<T>, $type<T>)>::from(*original);
+                self.admit(&candidate, &original)
+            }
+        }
+
+        impl<T: Float, const N: usize> Goodness<[$type<T>; N]> for CrudeIndependentAbsolute<T> {
+            fn admit(&mut self, candidate: &[$type<T>; N], original: &[$type<T>; N]) -> Option<bool> {
+                self.check(
+                    &candidate.each_ref().map(|curve| &curve[..]),
+                    &original.each_ref().map(|curve| &curve[..]),
+                )
+            }
+        }
+    )*);
 }
 
-#[rustfmt::skip]
-fn admit<T: Float>(one: [T; 4], other: [T; 4], distance: T) -> bool {
-    (one[0] - other[0]).abs() <= distance &&
-    (one[1] - other[1]).abs() <= distance &&
-    (one[2] - other[2]).abs() <= distance &&
-    (one[3] - other[3]).abs() <= distance
+implement!(Quadratic, Cubic);
+
+fn admit<T: Float>(one: &[T], other: &[T], distance: T) -> bool {
+    one.iter()
+        .zip(other)
+        .all(|(one, other)| (*one - *other).abs() <= distance)
 }
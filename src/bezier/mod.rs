@@ -1,12 +1,27 @@
 //! Bézier curves.
 
+use num_traits::Float;
+
+pub mod batch;
 pub mod goodness;
 
 mod align;
+mod area;
+mod derive;
+mod difference;
 mod evaluate;
 mod expand;
+mod frame;
+mod moments;
+mod point;
+mod polynomial;
+mod project;
 mod reduce;
+mod reparameterize;
+mod reverse;
 mod subdivide;
+mod transform;
+mod winding;
 
 /// A linear curve.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -20,6 +35,18 @@ pub struct Quadratic<T>([T; 3]);
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct Cubic<T>([T; 4]);
 
+/// A rational quadratic curve in two dimensions.
+///
+/// The control points share one array of weights.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RationalQuadratic<T>([T; 3], [T; 3], [T; 3]);
+
+/// A rational cubic curve in two dimensions.
+///
+/// The control points share one array of weights.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RationalCubic<T>([T; 4], [T; 4], [T; 4]);
+
 impl<T> Linear<T> {
     /// Create an instance.
     #[inline]
@@ -44,6 +71,65 @@ impl<T> Cubic<T> {
     }
 }
 
+macro_rules! implement {
+    ($($type:ident => ($polynomial:ident, $count:expr)),*) => ($(
+        impl<T> $type<T> {
+            /// Create an instance.
+            ///
+            /// The weights are expected to be positive.
+            #[inline]
+            pub fn new(x: [T; $count], y: [T; $count], weights: [T; $count]) -> Self {
+                Self(x, y, weights)
+            }
+
+            /// Return the horizontal coordinates of the control points.
+            #[inline]
+            pub fn x(&self) -> &[T; $count] {
+                &self.0
+            }
+
+            /// Return the vertical coordinates of the control points.
+            #[inline]
+            pub fn y(&self) -> &[T; $count] {
+                &self.1
+            }
+
+            /// Return the weights.
+            #[inline]
+            pub fn weights(&self) -> &[T; $count] {
+                &self.2
+            }
+        }
+
+        impl<T: Float> $type<T> {
+            fn homogenize(&self) -> ($polynomial<T>, $polynomial<T>, $polynomial<T>) {
+                let mut weights = self.2.iter();
+                let x = self.0.map(|value| value * *weights.next().unwrap());
+                let mut weights = self.2.iter();
+                let y = self.1.map(|value| value * *weights.next().unwrap());
+                ($polynomial(x), $polynomial(y), $polynomial(self.2))
+            }
+
+            fn dehomogenize(x: $polynomial<T>, y: $polynomial<T>, weights: $polynomial<T>) -> Self {
+                let mut divisors = weights.0.iter();
+                let x = x.0.map(|value| value / *divisors.next().unwrap());
+                let mut divisors = weights.0.iter();
+                let y = y.0.map(|value| value / *divisors.next().unwrap());
+                Self(x, y, weights.0)
+            }
+        }
+
+        impl<T: Float> From<($polynomial<T>, $polynomial<T>)> for $type<T> {
+            #[inline]
+            fn from((x, y): ($polynomial<T>, $polynomial<T>)) -> Self {
+                Self(x.0, y.0, [T::one(); $count])
+            }
+        }
+    )*);
+}
+
+implement!(RationalQuadratic => (Quadratic, 3), RationalCubic => (Cubic, 4));
+
 impl<T> std::ops::Deref for Linear<T> {
     type Target = [T; 2];
 
@@ -0,0 +1,77 @@
+// Reference:
+// https://en.wikipedia.org/wiki/Green%27s_theorem#Area_calculation
+
+use num_traits::Float;
+
+use crate::bezier::{Cubic, Linear, Quadratic};
+use crate::moments::{Moment, Moments};
+use crate::polynomial;
+
+macro_rules! implement {
+    ($($type:ident),*) => ($(
+        // The integrals over the region are turned into the ones along the curve, whose
+        // integrands are polynomials.
+        impl<T: Float> Moment<T> for ($type<T>, $type<T>) {
+            fn moments(&self) -> Moments<T> {
+                let (x, y) = (self.0.polynomial(), self.1.polynomial());
+                let (dx, dy) = (polynomial::derive(&x), polynomial::derive(&y));
+                let (xx, yy) = (polynomial::multiply(&x, &x), polynomial::multiply(&y, &y));
+                let integrate = |one: &[T], other: &[T]| {
+                    polynomial::integrate(&polynomial::multiply(one, other))
+                };
+                let (two, three) = (T::from(2).unwrap(), T::from(3).unwrap());
+                Moments {
+                    area: (integrate(&x, &dy) - integrate(&y, &dx)) / two,
+                    first: (integrate(&xx, &dy) / two, -integrate(&yy, &dx) / two),
+                    second: (
+                        integrate(&polynomial::multiply(&xx, &x), &dy) / three,
+                        -integrate(&polynomial::multiply(&yy, &y), &dx) / three,
+                        integrate(&polynomial::multiply(&xx, &y), &dy) / two,
+                    ),
+                }
+            }
+        }
+    )*);
+}
+
+implement!(Linear, Quadratic, Cubic);
+
+#[cfg(test)]
+mod tests {
+    use crate::area::Area;
+    use crate::bezier::{Cubic, Linear};
+    use crate::moments::Moment;
+
+    #[test]
+    fn area() {
+        let curve = (
+            Cubic::new(1.0, 0.0, 90.0, 100.0),
+            Cubic::new(2.0, 50.0, -7.0, 3.0),
+        );
+        assert::close(curve.moments().area, curve.area(), 1e-9);
+    }
+
+    #[test]
+    fn rectangle() {
+        let corners = [(1.0, 1.0), (5.0, 1.0), (5.0, 3.0), (1.0, 3.0), (1.0, 1.0)];
+        let curves = corners
+            .windows(2)
+            .map(|pair| {
+                (
+                    Linear::new(pair[0].0, pair[1].0),
+                    Linear::new(pair[0].1, pair[1].1),
+                )
+            })
+            .collect::<Vec<_>>();
+        let moments = curves.moments();
+        assert::close(moments.area, 8.0, 1e-12);
+        let (x, y) = moments.centroid().unwrap();
+        assert::close(&[x, y][..], &[3.0, 2.0][..], 1e-12);
+        let (xx, yy, xy) = moments.central().unwrap();
+        assert::close(
+            &[xx, yy, xy][..],
+            &[2.0 * 64.0 / 12.0, 4.0 * 8.0 / 12.0, 0.0][..],
+            1e-12,
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use num_traits::Float;
+
+use crate::align::Align;
+use crate::bezier::{Cubic, Linear, Quadratic};
+use crate::evaluate::Evaluate;
+use crate::expand::Expand;
+use crate::point::Point;
+use crate::reduce::Reduce;
+use crate::subdivide::Subdivide;
+
+macro_rules! implement {
+    ($($type:ident),*) => ($(
+        impl<T: Copy> From<($type<T>, $type<T>)> for $type<Point<T>> {
+            #[inline]
+            fn from((x, y): ($type<T>, $type<T>)) -> Self {
+                let mut y = y.0.into_iter();
+                Self(x.0.map(|x| Point::new(x, y.next().unwrap())))
+            }
+        }
+
+        impl<T: Copy> From<$type<Point<T>>> for ($type<T>, $type<T>) {
+            #[inline]
+            fn from(curve: $type<Point<T>>) -> Self {
+                ($type(curve.0.map(|point| point.x)), $type(curve.0.map(|point| point.y)))
+            }
+        }
+
+        impl<T> Evaluate<T> for $type<Point<T>>
+        where
+            T: Float,
+            $type<T>: Evaluate<T, Target = T>,
+        {
+            type Target = Point<T>;
+
+            #[inline]
+            fn evaluate(&self, t: T) -> Self::Target {
+                <($type<T>, $type<T>)>::from(*self).evaluate(t).into()
+            }
+        }
+
+        impl<T> Subdivide<T> for $type<Point<T>>
+        where
+            T: Float,
+            $type<T>: Subdivide<T>,
+        {
+            #[inline]
+            fn subdivide(&self, t: T) -> (Self, Self) {
+                let (head, tail) = <($type<T>, $type<T>)>::from(*self).subdivide(t);
+                (head.into(), tail.into())
+            }
+        }
+    )*);
+}
+
+implement!(Linear, Quadratic, Cubic);
+
+macro_rules! implement_order {
+    ($($lower:ident => $higher:ident),*) => ($(
+        impl<T> Reduce<T> for $higher<Point<T>>
+        where
+            T: Float,
+            $higher<T>: Reduce<T, Target = $lower<T>>,
+        {
+            type Target = $lower<Point<T>>;
+
+            #[inline]
+            fn reduce(&self) -> Self::Target {
+                <($higher<T>, $higher<T>)>::from(*self).reduce().into()
+            }
+        }
+
+        impl<T> Expand<T> for $lower<Point<T>>
+        where
+            T: Float,
+            $lower<T>: Expand<T, Target = $higher<T>>,
+        {
+            type Target = $higher<Point<T>>;
+
+            #[inline]
+            fn expand(&self) -> Self::Target {
+                <($lower<T>, $lower<T>)>::from(*self).expand().into()
+            }
+        }
+
+        impl<T> Align<T, $higher<Point<T>>> for $lower<Point<T>>
+        where
+            T: Float,
+            $lower<T>: Align<T, $higher<T>>,
+        {
+            #[inline]
+            fn align(self, other: &$higher<Point<T>>) -> Self {
+                let other = <($higher<T>, $higher<T>)>::from(*other);
+                <($lower<T>, $lower<T>)>::from(self).align(&other).into()
+            }
+        }
+    )*);
+}
+
+implement_order!(Linear => Quadratic, Quadratic => Cubic);
+
+#[cfg(test)]
+mod tests {
+    use crate::approximation::Approximation;
+    use crate::bezier::goodness::CrudeIndependentAbsolute;
+    use crate::bezier::{Cubic, Quadratic};
+    use crate::evaluate::Evaluate;
+    use crate::point::Point;
+
+    #[test]
+    fn convert() {
+        let x = Cubic::new(1.0, 3.0, 5.0, 6.0);
+        let y = Cubic::new(2.0, 1.0, 3.0, 2.0);
+        let curve = Cubic::from((x, y));
+        assert_eq!(
+            curve,
+            Cubic::new(
+                Point::new(1.0, 2.0),
+                Point::new(3.0, 1.0),
+                Point::new(5.0, 3.0),
+                Point::new(6.0, 2.0),
+            ),
+        );
+        assert_eq!(<(Cubic<_>, Cubic<_>)>::from(curve), (x, y));
+    }
+
+    #[test]
+    fn evaluate() {
+        let x = Cubic::new(1.0, 3.0, 5.0, 6.0);
+        let y = Cubic::new(2.0, 1.0, 3.0, 2.0);
+        let curve = Cubic::from((x, y));
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            assert_eq!(curve.evaluate(t), (x, y).evaluate(t).into());
+        }
+    }
+
+    #[test]
+    fn approximate() {
+        let x = Cubic::new(0.0, 0.0, 90.0, 100.0);
+        let y = Cubic::new(0.0, 50.0, 0.0, 0.0);
+        let goodness = CrudeIndependentAbsolute::new(1.0, f64::MAX, usize::MAX);
+        let expected = Approximation::new((x, y), goodness)
+            .map(Quadratic::from)
+            .collect::<Vec<_>>();
+        let goodness = CrudeIndependentAbsolute::new(1.0, f64::MAX, usize::MAX);
+        let actual = Approximation::new(Cubic::from((x, y)), goodness).collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,151 @@
+use num_traits::Float;
+
+use crate::bezier::{Cubic, Linear, Quadratic};
+
+impl<T: Float> Linear<T> {
+    /// Create an instance from the coefficients in the power basis in the ascending order of
+    /// powers.
+    #[inline]
+    pub fn from_polynomial(coefficients: [T; 2]) -> Self {
+        let [a, b] = coefficients;
+        Self([a, a + b])
+    }
+
+    /// Return the coefficients in the power basis in the ascending order of powers.
+    pub fn polynomial(&self) -> [T; 2] {
+        [self[0], self[1] - self[0]]
+    }
+}
+
+impl<T: Float> Quadratic<T> {
+    /// Create an instance from the coefficients in the power basis in the ascending order of
+    /// powers.
+    pub fn from_polynomial(coefficients: [T; 3]) -> Self {
+        let [a, b, c] = coefficients;
+        let half = b / (T::one() + T::one());
+        Self([a, a + half, a + b + c])
+    }
+
+    /// Return the coefficients in the power basis in the ascending order of powers.
+    pub fn polynomial(&self) -> [T; 3] {
+        let two = T::one() + T::one();
+        [
+            self[0],
+            two * (self[1] - self[0]),
+            self[0] - two * self[1] + self[2],
+        ]
+    }
+}
+
+impl<T: Float> Cubic<T> {
+    /// Create an instance from the coefficients in the power basis in the ascending order of
+    /// powers.
+    pub fn from_polynomial(coefficients: [T; 4]) -> Self {
+        let [a, b, c, d] = coefficients;
+        let three = T::one() + T::one() + T::one();
+        Self([a, a + b / three, a + (b + b + c) / three, a + b + c + d])
+    }
+
+    /// Create an instance from the endpoints and the derivatives at them in the Hermite form.
+    pub fn from_hermite(start: T, start_tangent: T, end: T, end_tangent: T) -> Self {
+        let three = T::one() + T::one() + T::one();
+        Self([
+            start,
+            start + start_tangent / three,
+            end - end_tangent / three,
+            end,
+        ])
+    }
+
+    /// Create an instance from four consecutive points of a uniform Catmull–Rom spline.
+    ///
+    /// The curve goes from the second point to the third one.
+    pub fn from_catmull_rom(a: T, b: T, c: T, d: T) -> Self {
+        let six = T::from(6).unwrap();
+        Self([b, b + (c - a) / six, c - (d - b) / six, c])
+    }
+
+    /// Return the coefficients in the power basis in the ascending order of powers.
+    pub fn polynomial(&self) -> [T; 4] {
+        let three = T::one() + T::one() + T::one();
+        [
+            self[0],
+            three * (self[1] - self[0]),
+            three * (self[0] - self[1] - self[1] + self[2]),
+            self[3] - self[0] + three * (self[1] - self[2]),
+        ]
+    }
+
+    /// Return the endpoints and the derivatives at them in the Hermite form.
+    pub fn hermite(&self) -> [T; 4] {
+        let three = T::one() + T::one() + T::one();
+        [
+            self[0],
+            three * (self[1] - self[0]),
+            self[3],
+            three * (self[3] - self[2]),
+        ]
+    }
+
+    /// Return the four points of a uniform Catmull–Rom spline that produce the curve.
+    pub fn catmull_rom(&self) -> [T; 4] {
+        let six = T::from(6).unwrap();
+        [
+            self[3] - six * (self[1] - self[0]),
+            self[0],
+            self[3],
+            self[0] + six * (self[3] - self[2]),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bezier::{Cubic, Linear, Quadratic};
+    use crate::derive::Derive;
+    use crate::evaluate::Evaluate;
+    use crate::polynomial;
+
+    #[test]
+    fn polynomial() {
+        let curve = Linear::new(1.0, 5.0);
+        assert_eq!(curve.polynomial(), [1.0, 4.0]);
+        assert_eq!(Linear::from_polynomial(curve.polynomial()), curve);
+        let curve = Quadratic::new(1.0, 3.0, 2.0);
+        assert_eq!(curve.polynomial(), [1.0, 4.0, -3.0]);
+        assert_eq!(Quadratic::from_polynomial(curve.polynomial()), curve);
+        let curve = Cubic::new(1.0, 3.0, 5.0, 6.0);
+        assert_eq!(curve.polynomial(), [1.0, 6.0, 0.0, -1.0]);
+        assert_eq!(Cubic::from_polynomial(curve.polynomial()), curve);
+        for t in [0.0, 0.25, 0.5, 1.0] {
+            assert::close(
+                polynomial::evaluate(&curve.polynomial(), t),
+                curve.evaluate(t),
+                1e-12,
+            );
+        }
+    }
+
+    #[test]
+    fn hermite() {
+        let curve = Cubic::new(1.0, 3.0, 5.0, 6.0);
+        let [start, start_tangent, end, end_tangent] = curve.hermite();
+        assert_eq!([start, end], [1.0, 6.0]);
+        assert_eq!(start_tangent, curve.derive().evaluate(0.0));
+        assert_eq!(end_tangent, curve.derive().evaluate(1.0));
+        assert_eq!(
+            Cubic::from_hermite(start, start_tangent, end, end_tangent),
+            curve,
+        );
+    }
+
+    #[test]
+    fn catmull_rom() {
+        let curve = Cubic::from_catmull_rom(0.0, 6.0, 12.0, 6.0);
+        assert_eq!(curve, Cubic::new(6.0, 8.0, 12.0, 12.0));
+        assert_eq!(curve.catmull_rom(), [0.0, 6.0, 12.0, 6.0]);
+        let derivative = curve.derive();
+        assert_eq!(derivative.evaluate(0.0), (12.0 - 0.0) / 2.0);
+        assert_eq!(derivative.evaluate(1.0), (6.0 - 6.0) / 2.0);
+    }
+}
@@ -0,0 +1,96 @@
+use num_traits::Float;
+
+use crate::bezier::{Cubic, Linear, Quadratic};
+use crate::polynomial;
+use crate::project::{Project, Projection};
+
+macro_rules! implement {
+    ($($type:ident),*) => ($(
+        // The closest point is either an endpoint or a root of the dot product of the derivative
+        // and the vector from the point, which is of degree 5 for cubic curves.
+        impl<T: Float> Project<T> for ($type<T>, $type<T>) {
+            fn project(&self, point: (T, T)) -> Projection<T> {
+                let mut x = self.0.polynomial();
+                let mut y = self.1.polynomial();
+                x[0] = x[0] - point.0;
+                y[0] = y[0] - point.1;
+                let slope = polynomial::add(
+                    &polynomial::multiply(&x, &polynomial::derive(&x)),
+                    &polynomial::multiply(&y, &polynomial::derive(&y)),
+                );
+                let mut candidates = polynomial::roots(&slope, (T::zero(), T::one()));
+                candidates.extend([T::zero(), T::one()]);
+                candidates
+                    .into_iter()
+                    .map(|t| {
+                        let (u, v) = (polynomial::evaluate(&x, t), polynomial::evaluate(&y, t));
+                        Projection {
+                            parameter: t,
+                            point: (u + point.0, v + point.1),
+                            distance: u.hypot(v),
+                        }
+                    })
+                    .fold(None, |best: Option<Projection<T>>, candidate| match best {
+                        Some(best) if best.distance <= candidate.distance => Some(best),
+                        _ => Some(candidate),
+                    })
+                    .unwrap()
+            }
+        }
+    )*);
+}
+
+implement!(Linear, Quadratic, Cubic);
+
+#[cfg(test)]
+mod tests {
+    use crate::bezier::{Cubic, Linear, Quadratic};
+    use crate::evaluate::Evaluate;
+    use crate::project::Project;
+
+    #[test]
+    fn linear() {
+        let curve = (Linear::new(0.0, 10.0), Linear::new(0.0, 0.0));
+        let projection = curve.project((4.0, 3.0));
+        assert::close(projection.parameter, 0.4, 1e-12);
+        assert::close(projection.distance, 3.0, 1e-12);
+        let projection = curve.project((-3.0, 4.0));
+        assert_eq!(projection.parameter, 0.0);
+        assert_eq!(projection.point, (0.0, 0.0));
+        assert_eq!(projection.distance, 5.0);
+    }
+
+    #[test]
+    fn quadratic() {
+        let curve = (
+            Quadratic::new(-1.0, 0.0, 1.0),
+            Quadratic::new(1.0, -1.0, 1.0),
+        );
+        let projection = curve.project((0.0, 1.0));
+        assert::close(projection.distance, 0.75f64.sqrt(), 1e-12);
+        assert::close(projection.point.1, 0.5, 1e-12);
+        let projection = curve.project((0.0, -1.0));
+        assert::close(projection.parameter, 0.5, 1e-12);
+        assert::close(projection.distance, 1.0, 1e-12);
+    }
+
+    #[test]
+    fn cubic() {
+        let curve = (
+            Cubic::new(0.0, 0.0, 90.0, 100.0),
+            Cubic::new(0.0, 50.0, 0.0, 0.0),
+        );
+        for &t in &[0.1, 0.3, 0.5, 0.7, 0.9] {
+            let (x, y) = curve.evaluate(t);
+            let projection = curve.project((x, y));
+            assert::close(projection.parameter, t, 1e-9);
+            assert::close(projection.distance, 0.0, 1e-9);
+        }
+        let point = (40.0, 40.0);
+        let projection = curve.project(point);
+        for i in 0..=1000 {
+            let (x, y) = curve.evaluate(i as f64 / 1000.0);
+            assert!(projection.distance <= (x - point.0).hypot(y - point.1) + 1e-12);
+        }
+    }
+}
@@ -1,11 +1,22 @@
 // Reference:
 // https://pomax.github.io/bezierinfo/#reordering
 
-use crate::bezier::{Cubic, Quadratic};
+use crate::bezier::{Cubic, Linear, Quadratic};
 use crate::reduce::Reduce;
 
 macro_rules! implement {
     ($($type:ty),*) => ($(
+        impl Reduce<$type> for Quadratic<$type> {
+            type Target = Linear<$type>;
+
+            fn reduce(&self) -> Self::Target {
+                Self::Target::new(
+                    (5.0 * self[0] + 2.0 * self[1] - self[2]) / 6.0,
+                    (5.0 * self[2] + 2.0 * self[1] - self[0]) / 6.0,
+                )
+            }
+        }
+
         impl Reduce<$type> for Cubic<$type> {
             type Target = Quadratic<$type>;
 
@@ -24,7 +35,7 @@ implement!(f32, f64);
 
 #[cfg(test)]
 mod tests {
-    use crate::bezier::{Cubic, Quadratic};
+    use crate::bezier::{Cubic, Linear, Quadratic};
     use crate::reduce::Reduce;
 
     #[test]
@@ -34,4 +45,19 @@ mod tests {
         assert_eq!(x, Quadratic::new(-10.0, 50.0, 110.0));
         assert_eq!(y, Quadratic::new(0.0, 150.0, 0.0));
     }
+
+    #[test]
+    fn reduce_quadratic() {
+        let x = Quadratic::new(0.0, 60.0, 120.0).reduce();
+        let y = Quadratic::new(0.0, 60.0, 0.0).reduce();
+        assert_eq!(x, Linear::new(0.0, 120.0));
+        assert_eq!(y, Linear::new(20.0, 20.0));
+    }
+
+    #[test]
+    fn reduce_cubic_twice() {
+        let x: Quadratic<f64> = Cubic::new(0.0, 40.0, 80.0, 120.0).reduce();
+        let x = x.reduce();
+        assert_eq!(x, Linear::new(0.0, 120.0));
+    }
 }
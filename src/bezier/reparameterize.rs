@@ -0,0 +1,102 @@
+// Reference:
+// https://en.wikipedia.org/wiki/Blossom_(functional)
+
+use num_traits::Float;
+
+use crate::bezier::{Cubic, Linear, Quadratic, RationalCubic, RationalQuadratic};
+use crate::reparameterize::Reparameterize;
+
+macro_rules! implement {
+    ($($type:ident),*) => ($(
+        // The control points are the values of the blossom with the arguments being the start
+        // and the end of the interval repeated accordingly.
+        impl<T: Float> Reparameterize<T> for $type<T> {
+            fn reparameterize(&self, start: T, end: T) -> Self {
+                let degree = self.0.len() - 1;
+                let mut curve = *self;
+                for (i, value) in curve.0.iter_mut().enumerate() {
+                    let mut beta = self.0;
+                    for level in 0..degree {
+                        let t = if level < degree - i { start } else { end };
+                        let u = T::one() - t;
+                        for j in 0..degree - level {
+                            beta[j] = beta[j] * u + beta[j + 1] * t;
+                        }
+                    }
+                    *value = beta[0];
+                }
+                curve
+            }
+        }
+    )*);
+}
+
+implement!(Linear, Quadratic, Cubic);
+
+macro_rules! implement_rational {
+    ($($type:ident),*) => ($(
+        impl<T: Float> Reparameterize<T> for $type<T> {
+            fn reparameterize(&self, start: T, end: T) -> Self {
+                let (x, y, weights) = self.homogenize();
+                Self::dehomogenize(
+                    x.reparameterize(start, end),
+                    y.reparameterize(start, end),
+                    weights.reparameterize(start, end),
+                )
+            }
+        }
+    )*);
+}
+
+implement_rational!(RationalQuadratic, RationalCubic);
+
+#[cfg(test)]
+mod tests {
+    use crate::bezier::{Cubic, Linear, Quadratic, RationalQuadratic};
+    use crate::evaluate::Evaluate;
+    use crate::reparameterize::Reparameterize;
+    use crate::subdivide::Subdivide;
+
+    #[test]
+    fn cubic() {
+        let curve = Cubic::new(0.0, 3.0, -2.0, 5.0);
+        let (head, tail) = curve.subdivide(0.25);
+        assert::close(&curve.reparameterize(0.0, 0.25)[..], &head[..], 1e-12);
+        assert::close(&curve.reparameterize(0.25, 1.0)[..], &tail[..], 1e-12);
+        let extended = curve.reparameterize(-1.0, 2.0);
+        for &t in &[0.0, 0.2, 0.5, 1.0] {
+            let s = -1.0 + 3.0 * t;
+            let expected = ((curve.polynomial()[3] * s + curve.polynomial()[2]) * s
+                + curve.polynomial()[1])
+                * s
+                + curve.polynomial()[0];
+            assert::close(extended.evaluate(t), expected, 1e-9);
+        }
+    }
+
+    #[test]
+    fn linear() {
+        let curve = Linear::new(1.0, 3.0);
+        assert_eq!(curve.reparameterize(-1.0, 2.0), Linear::new(-1.0, 5.0));
+        assert_eq!(curve.reparameterize(1.0, 0.0), Linear::new(3.0, 1.0));
+    }
+
+    #[test]
+    fn quadratic() {
+        let curve = Quadratic::new(0.0, 4.0, 0.0);
+        let reversed = curve.reparameterize(1.0, 0.0);
+        assert_eq!(reversed, Quadratic::new(0.0, 4.0, 0.0));
+        assert_eq!(curve.reparameterize(0.0, 1.0), curve);
+    }
+
+    #[test]
+    fn rational() {
+        let weights = [1.0, std::f64::consts::FRAC_1_SQRT_2, 1.0];
+        let curve = RationalQuadratic::new([1.0, 1.0, 0.0], [0.0, 1.0, 1.0], weights);
+        let part = curve.reparameterize(0.2, 0.7);
+        for (s, t) in [(0.0, 0.2), (0.5, 0.45), (1.0, 0.7)] {
+            let ((x, y), (u, v)) = (part.evaluate(s), curve.evaluate(t));
+            assert::close(&[x, y][..], &[u, v][..], 1e-12);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use num_traits::Float;
+
+use crate::bezier::{Cubic, Linear, Quadratic, RationalCubic, RationalQuadratic};
+use crate::reverse::Reverse;
+
+macro_rules! implement {
+    ($($type:ident),*) => ($(
+        impl<T: Float> Reverse<T> for $type<T> {
+            #[inline]
+            fn reverse(&self) -> Self {
+                let mut curve = *self;
+                curve.0.reverse();
+                curve
+            }
+        }
+    )*);
+}
+
+implement!(Linear, Quadratic, Cubic);
+
+macro_rules! implement_rational {
+    ($($type:ident),*) => ($(
+        impl<T: Float> Reverse<T> for $type<T> {
+            #[inline]
+            fn reverse(&self) -> Self {
+                let mut curve = *self;
+                curve.0.reverse();
+                curve.1.reverse();
+                curve.2.reverse();
+                curve
+            }
+        }
+    )*);
+}
+
+implement_rational!(RationalQuadratic, RationalCubic);
+
+#[cfg(test)]
+mod tests {
+    use crate::bezier::{Cubic, Linear, RationalQuadratic};
+    use crate::evaluate::Evaluate;
+    use crate::reverse::Reverse;
+
+    #[test]
+    fn cubic() {
+        let curve = (
+            Cubic::new(0.0, 0.0, 90.0, 100.0),
+            Cubic::new(0.0, 50.0, 0.0, 0.0),
+        );
+        let reversed = curve.reverse();
+        assert_eq!(reversed.0, Cubic::new(100.0, 90.0, 0.0, 0.0));
+        for &t in &[0.0, 0.3, 0.5, 1.0] {
+            let (x, y) = reversed.evaluate(t);
+            let (u, v) = curve.evaluate(1.0 - t);
+            assert::close(&[x, y][..], &[u, v][..], 1e-12);
+        }
+    }
+
+    #[test]
+    fn linear() {
+        assert_eq!(
+            [Linear::new(1.0, 2.0), Linear::new(3.0, 4.0)].reverse(),
+            [Linear::new(2.0, 1.0), Linear::new(4.0, 3.0)],
+        );
+    }
+
+    #[test]
+    fn rational() {
+        let curve = RationalQuadratic::new([1.0, 1.0, 0.0], [0.0, 1.0, 1.0], [1.0, 2.0, 3.0]);
+        let reversed = curve.reverse();
+        assert_eq!(reversed.weights(), &[3.0, 2.0, 1.0]);
+        let ((x, y), (u, v)) = (reversed.evaluate(0.25), curve.evaluate(0.75));
+        assert::close(&[x, y][..], &[u, v][..], 1e-12);
+    }
+}
@@ -3,15 +3,58 @@
 
 use num_traits::Float;
 
-use crate::bezier::Cubic;
+use crate::bezier::{Cubic, Linear, Quadratic, RationalCubic, RationalQuadratic};
 use crate::subdivide::Subdivide;
 
+impl<T> Subdivide<T> for Linear<T>
+where
+    T: Float,
+{
+    fn subdivide(&self, t: T) -> (Self, Self) {
+        debug_assert!(T::zero() <= t && t <= T::one());
+        let u = T::one() - t;
+        let beta = self.0;
+        let middle = beta[0] * u + beta[1] * t;
+        (Self([beta[0], middle]), Self([middle, beta[1]]))
+    }
+}
+
+impl<T> Subdivide<T> for Quadratic<T>
+where
+    T: Float + Default,
+{
+    fn subdivide(&self, t: T) -> (Self, Self) {
+        debug_assert!(T::zero() <= t && t <= T::one());
+        let u = T::one() - t;
+        let mut beta = self.0;
+
+        let mut head: [T; 3] = Default::default();
+        let mut tail: [T; 3] = Default::default();
+
+        head[0] = beta[0];
+
+        beta[0] = beta[0] * u + beta[1] * t;
+        head[1] = beta[0];
+
+        beta[1] = beta[1] * u + beta[2] * t;
+        tail[1] = beta[1];
+
+        beta[0] = beta[0] * u + beta[1] * t;
+        head[2] = beta[0];
+        tail[0] = beta[0];
+
+        tail[2] = beta[2];
+
+        (Self(head), Self(tail))
+    }
+}
+
 impl<T> Subdivide<T> for Cubic<T>
 where
     T: Float + Default,
 {
     fn subdivide(&self, t: T) -> (Self, Self) {
-        debug_assert!(T::zero() < t && t < T::one());
+        debug_assert!(T::zero() <= t && t <= T::one());
         let u = T::one() - t;
         let mut beta = self.0;
 
@@ -44,11 +87,88 @@ where
     }
 }
 
+macro_rules! implement {
+    ($($type:ident),*) => ($(
+        impl<T> Subdivide<T> for $type<T>
+        where
+            T: Float + Default,
+        {
+            fn subdivide(&self, t: T) -> (Self, Self) {
+                let (x, y, weights) = self.homogenize();
+                let (x, y, weights) = (x.subdivide(t), y.subdivide(t), weights.subdivide(t));
+                (
+                    Self::dehomogenize(x.0, y.0, weights.0),
+                    Self::dehomogenize(x.1, y.1, weights.1),
+                )
+            }
+        }
+    )*);
+}
+
+implement!(RationalQuadratic, RationalCubic);
+
 #[cfg(test)]
 mod tests {
-    use crate::bezier::Cubic;
+    use crate::bezier::{Cubic, Linear, Quadratic, RationalQuadratic};
+    use crate::evaluate::Evaluate;
     use crate::subdivide::Subdivide;
 
+    #[test]
+    fn linear() {
+        let x = Linear::new(2.0, 8.0);
+        assert_eq!(
+            x.subdivide(0.5),
+            (Linear::new(2.0, 5.0), Linear::new(5.0, 8.0)),
+        );
+    }
+
+    #[test]
+    fn quadratic() {
+        let x = Quadratic::new(0.0, 4.0, 0.0);
+        assert_eq!(
+            x.subdivide(0.5),
+            (Quadratic::new(0.0, 2.0, 2.0), Quadratic::new(2.0, 2.0, 0.0),),
+        );
+    }
+
+    #[test]
+    fn extract() {
+        let x = Cubic::new(0.0, 3.0, 6.0, 9.0);
+        assert_eq!(x.extract(0.0, 1.0), x);
+        let part = x.extract(0.25, 0.75);
+        assert::close(&part[..], &[2.25, 3.75, 5.25, 6.75][..], 1e-12);
+        let part = x.extract(0.5, 1.0);
+        assert::close(&part[..], &[4.5, 6.0, 7.5, 9.0][..], 1e-12);
+        let part = x.extract(0.5, 0.5);
+        assert::close(&part[..], &[4.5, 4.5, 4.5, 4.5][..], 1e-12);
+        assert_eq!(x.extract(1.0, 0.5), Cubic::new(9.0, 9.0, 9.0, 9.0));
+    }
+
+    #[test]
+    fn array() {
+        let curve = [Linear::new(2.0, 8.0), Linear::new(0.0, 4.0)];
+        assert_eq!(
+            curve.subdivide(0.25),
+            (
+                [Linear::new(2.0, 3.5), Linear::new(0.0, 1.0)],
+                [Linear::new(3.5, 8.0), Linear::new(1.0, 4.0)],
+            ),
+        );
+    }
+
+    #[test]
+    fn rational() {
+        let weights = [1.0, std::f64::consts::FRAC_1_SQRT_2, 1.0];
+        let curve = RationalQuadratic::new([1.0, 1.0, 0.0], [0.0, 1.0, 1.0], weights);
+        let (head, tail) = curve.subdivide(0.3);
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            for (x, y) in [curve.evaluate(t), head.evaluate(t), tail.evaluate(t)] {
+                assert::close(x * x + y * y, 1.0, 1e-15);
+            }
+        }
+        assert::close(head.evaluate(1.0).0, curve.evaluate(0.3).0, 1e-15);
+    }
+
     #[test]
     fn subdivide() {
         let x = Cubic::new(2.0, 4.0, 6.0, 8.0);
@@ -0,0 +1,191 @@
+use num_traits::Float;
+
+use crate::bezier::{Cubic, Linear, Quadratic, RationalCubic, RationalQuadratic};
+use crate::point::Point;
+use crate::transform::{Affine, Projective, Transform};
+
+macro_rules! implement {
+    ($($type:ident),*) => ($(
+        impl<T: Float> Transform<T, Affine<T>> for ($type<T>, $type<T>) {
+            type Target = Self;
+
+            fn transform(&self, transformation: &Affine<T>) -> Self::Target {
+                let (mut x, mut y) = *self;
+                for i in 0..x.len() {
+                    (x[i], y[i]) = transformation.apply((x[i], y[i]));
+                }
+                (x, y)
+            }
+        }
+    )*);
+}
+
+implement!(Linear, Quadratic, Cubic);
+
+macro_rules! implement_rational {
+    ($($type:ident),*) => ($(
+        impl<T: Float> Transform<T, Affine<T>> for $type<T> {
+            type Target = Self;
+
+            fn transform(&self, transformation: &Affine<T>) -> Self::Target {
+                let mut curve = *self;
+                for i in 0..curve.2.len() {
+                    (curve.0[i], curve.1[i]) = transformation.apply((self.0[i], self.1[i]));
+                }
+                curve
+            }
+        }
+    )*);
+}
+
+implement_rational!(RationalQuadratic, RationalCubic);
+
+macro_rules! implement_point {
+    ($($type:ident),*) => ($(
+        impl<T: Float> Transform<T, Affine<T>> for $type<Point<T>> {
+            type Target = Self;
+
+            #[inline]
+            fn transform(&self, transformation: &Affine<T>) -> Self::Target {
+                Self(self.0.map(|point| transformation.apply(point.into()).into()))
+            }
+        }
+    )*);
+}
+
+implement_point!(Linear, Quadratic, Cubic);
+
+macro_rules! implement_projective {
+    ($($type:ident => $rational:ident),*) => ($(
+        impl<T: Float> Transform<T, Projective<T>> for ($type<T>, $type<T>) {
+            type Target = Option<$rational<T>>;
+
+            #[inline]
+            fn transform(&self, transformation: &Projective<T>) -> Self::Target {
+                $rational::from(*self).transform(transformation)
+            }
+        }
+
+        // The weights are the homogeneous coordinates of the transformed control points. If they
+        // are all negative, they are negated, which leaves the curve the same; if they differ in
+        // sign or vanish, the curve can pass through infinity and cannot be represented.
+        impl<T: Float> Transform<T, Projective<T>> for $rational<T> {
+            type Target = Option<Self>;
+
+            fn transform(&self, transformation: &Projective<T>) -> Self::Target {
+                let mut curve = *self;
+                for i in 0..curve.2.len() {
+                    let w = self.2[i];
+                    let point = [self.0[i] * w, self.1[i] * w, w];
+                    let [u, v, w] = transformation.apply_homogeneous(point);
+                    (curve.0[i], curve.1[i], curve.2[i]) = (u / w, v / w, w);
+                }
+                let zero = T::zero();
+                if curve.2.iter().all(|w| *w < zero) {
+                    curve.2 = curve.2.map(|w| -w);
+                }
+                if curve.2.iter().all(|w| *w > zero && w.is_finite()) {
+                    Some(curve)
+                } else {
+                    None
+                }
+            }
+        }
+    )*);
+}
+
+implement_projective!(Quadratic => RationalQuadratic, Cubic => RationalCubic);
+
+#[cfg(test)]
+mod tests {
+    use crate::bezier::{Cubic, Linear, Quadratic, RationalQuadratic};
+    use crate::evaluate::Evaluate;
+    use crate::point::Point;
+    use crate::transform::{Affine, Projective, Transform};
+
+    #[test]
+    fn rotate() {
+        let x = Quadratic::new(0.0, 1.0, 2.0);
+        let y = Quadratic::new(0.0, 1.0, 0.0);
+        let (x, y) = (x, y).transform(&Affine::rotate(std::f64::consts::FRAC_PI_2));
+        for (actual, expected) in x.iter().zip([0.0, -1.0, 0.0]) {
+            assert::close(*actual, expected, 1e-15);
+        }
+        for (actual, expected) in y.iter().zip([0.0, 1.0, 2.0]) {
+            assert::close(*actual, expected, 1e-15);
+        }
+    }
+
+    #[test]
+    fn skew() {
+        let x = Linear::new(0.0, 0.0);
+        let y = Linear::new(0.0, 100.0);
+        let (x, y) = (x, y).transform(&Affine::skew(std::f64::consts::FRAC_PI_4, 0.0));
+        assert::close(x[1], 100.0, 1e-12);
+        assert_eq!(y, Linear::new(0.0, 100.0));
+    }
+
+    #[test]
+    fn invariant() {
+        let x = Cubic::new(1.0, 3.0, 5.0, 6.0);
+        let y = Cubic::new(2.0, 1.0, 3.0, 2.0);
+        let transformation = Affine::new(0.5, 1.5, -2.0, 0.25, 3.0, -4.0);
+        let curve = (x, y).transform(&transformation);
+        let other = Cubic::from((x, y)).transform(&transformation);
+        for t in [0.0, 0.2, 0.4, 0.6, 0.8, 1.0] {
+            let point = transformation.apply((x, y).evaluate(t));
+            let (x, y) = curve.evaluate(t);
+            assert::close(x, point.0, 1e-14);
+            assert::close(y, point.1, 1e-14);
+            assert_eq!(other.evaluate(t), Point::from(curve.evaluate(t)));
+        }
+    }
+
+    #[test]
+    fn projective() {
+        let x = Cubic::new(1.0, 3.0, 5.0, 6.0);
+        let y = Cubic::new(2.0, 1.0, 3.0, 2.0);
+        let transformation = Projective::new([[1.0, 0.5, 0.0], [0.0, 2.0, 1.0], [0.1, 0.2, 1.0]]);
+        let curve = (x, y).transform(&transformation).unwrap();
+        for t in [0.0, 0.2, 0.4, 0.6, 0.8, 1.0] {
+            let point = transformation.apply((x, y).evaluate(t));
+            let (x, y) = curve.evaluate(t);
+            assert::close(x, point.0, 1e-14);
+            assert::close(y, point.1, 1e-14);
+        }
+        let other = curve.transform(&transformation).unwrap();
+        let transformation = transformation.then(&transformation);
+        for t in [0.0, 0.2, 0.4, 0.6, 0.8, 1.0] {
+            let point = transformation.apply((x, y).evaluate(t));
+            let (x, y) = other.evaluate(t);
+            assert::close(x, point.0, 1e-14);
+            assert::close(y, point.1, 1e-14);
+        }
+    }
+
+    #[test]
+    fn infinity() {
+        let x = Quadratic::new(-1.0, 0.0, 1.0);
+        let y = Quadratic::new(0.0, 1.0, 0.0);
+        let transformation = Projective::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 0.0, 0.0]]);
+        assert_eq!((x, y).transform(&transformation), None);
+        let transformation = Projective::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -2.0]]);
+        let curve = (x, y).transform(&transformation).unwrap();
+        assert_eq!(curve.weights(), &[2.0, 2.0, 2.0]);
+        assert_eq!(curve.evaluate(0.5), (0.0, -0.25));
+    }
+
+    #[test]
+    fn rational() {
+        let weights = [1.0, std::f64::consts::FRAC_1_SQRT_2, 1.0];
+        let curve = RationalQuadratic::new([1.0, 1.0, 0.0], [0.0, 1.0, 1.0], weights);
+        let transformation = Affine::scale(2.0, 3.0).then(&Affine::translate(1.0, -1.0));
+        let other = curve.transform(&transformation);
+        assert_eq!(other.weights(), &weights);
+        for t in [0.0, 0.3, 0.5, 1.0] {
+            let point = transformation.apply(curve.evaluate(t));
+            let (x, y) = other.evaluate(t);
+            assert::close(&[x, y][..], &[point.0, point.1][..], 1e-14);
+        }
+    }
+}
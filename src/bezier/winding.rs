@@ -0,0 +1,121 @@
+use num_traits::Float;
+
+use crate::bezier::{Cubic, Linear, Quadratic};
+use crate::evaluate::Evaluate;
+use crate::polynomial;
+use crate::winding::Winding;
+
+const ITERATIONS: usize = 200;
+
+macro_rules! implement {
+    ($($type:ident),*) => ($(
+        // The curve is split into monotonic parts in the y-direction at the roots of the
+        // derivative, and each part crosses the ray at most once.
+        impl<T: Float> Winding<T> for ($type<T>, $type<T>) {
+            fn winding(&self, (x, y): (T, T)) -> isize {
+                let (one, zero) = (T::one(), T::zero());
+                let derivative = polynomial::derive(&self.1.polynomial());
+                let mut points = vec![zero];
+                points.extend(
+                    polynomial::roots(&derivative, (zero, one))
+                        .into_iter()
+                        .filter(|t| zero < *t && *t < one),
+                );
+                points.push(one);
+                let mut winding = 0;
+                for pair in points.windows(2) {
+                    let (mut a, mut b) = (pair[0], pair[1]);
+                    let (ya, yb) = (self.1.evaluate(a), self.1.evaluate(b));
+                    let sign = if ya <= y && y < yb {
+                        1
+                    } else if yb <= y && y < ya {
+                        -1
+                    } else {
+                        continue;
+                    };
+                    let upward = sign > 0;
+                    for _ in 0..ITERATIONS {
+                        let c = (a + b) / (one + one);
+                        if c <= a || c >= b {
+                            break;
+                        }
+                        if (self.1.evaluate(c) <= y) == upward {
+                            a = c;
+                        } else {
+                            b = c;
+                        }
+                    }
+                    let t = if upward { a } else { b };
+                    if self.0.evaluate(t) > x {
+                        winding += sign;
+                    }
+                }
+                winding
+            }
+        }
+    )*);
+}
+
+implement!(Linear, Quadratic, Cubic);
+
+#[cfg(test)]
+mod tests {
+    use crate::bezier::{Cubic, Linear, Quadratic};
+    use crate::winding::{Fill, Winding};
+
+    #[test]
+    fn linear() {
+        let curve = (Linear::new(1.0, 1.0), Linear::new(0.0, 2.0));
+        assert_eq!(curve.winding((0.0, 1.0)), 1);
+        assert_eq!(curve.winding((2.0, 1.0)), 0);
+        assert_eq!(curve.winding((0.0, 0.0)), 1);
+        assert_eq!(curve.winding((0.0, 2.0)), 0);
+        assert_eq!(curve.winding((0.0, 3.0)), 0);
+        let curve = (Linear::new(0.0, 2.0), Linear::new(1.0, 1.0));
+        assert_eq!(curve.winding((-1.0, 1.0)), 0);
+    }
+
+    #[test]
+    fn tangent() {
+        let curve = (Quadratic::new(0.0, 1.0, 2.0), Quadratic::new(0.0, 2.0, 0.0));
+        assert_eq!(curve.winding((-1.0, 1.0)), 0);
+        assert_eq!(curve.winding((-1.0, 0.5)), 0);
+        assert_eq!(curve.winding((0.5, 0.5)), -1);
+        assert_eq!(curve.winding((1.0, 0.5)), -1);
+        assert_eq!(curve.winding((1.8, 0.5)), 0);
+    }
+
+    #[test]
+    fn circle() {
+        let k = 0.5522847498307936;
+        let curves = [
+            (Cubic::new(1.0, 1.0, k, 0.0), Cubic::new(0.0, k, 1.0, 1.0)),
+            (
+                Cubic::new(0.0, -k, -1.0, -1.0),
+                Cubic::new(1.0, 1.0, k, 0.0),
+            ),
+            (
+                Cubic::new(-1.0, -1.0, -k, 0.0),
+                Cubic::new(0.0, -k, -1.0, -1.0),
+            ),
+            (
+                Cubic::new(0.0, k, 1.0, 1.0),
+                Cubic::new(-1.0, -1.0, -k, 0.0),
+            ),
+        ];
+        for point in [
+            (0.0, 0.0),
+            (0.5, 0.0),
+            (0.0, 0.99),
+            (0.0, -0.99),
+            (-0.7, 0.7),
+        ] {
+            assert_eq!(curves.winding(point), 1);
+            assert!(curves.encloses(point, Fill::EvenOdd));
+        }
+        for point in [(1.1, 0.0), (0.0, 1.0), (-0.8, 0.8), (5.0, 5.0)] {
+            assert_eq!(curves.winding(point), 0);
+            assert!(!curves.encloses(point, Fill::NonZero));
+        }
+    }
+}
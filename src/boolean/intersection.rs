@@ -0,0 +1,294 @@
+use std::cmp::Ordering;
+
+use num_traits::Float;
+
+use crate::derive::Derive;
+use crate::evaluate::Evaluate;
+use crate::path::Segment;
+use crate::project::Project;
+use crate::subdivide::Subdivide;
+
+const DEPTH: usize = 64;
+const ITERATIONS: usize = 8;
+const LIMIT: usize = 64;
+
+/// Find the parameters at which two segments intersect.
+///
+/// The segments are subdivided until the bounding boxes of their control points either stop
+/// overlapping or get smaller than the tolerance. If there are too many candidates, the segments
+/// are considered to overlap, and their endpoints lying on the other segment are taken instead.
+pub fn find<T>(one: &Segment<T>, other: &Segment<T>, tolerance: T) -> Vec<(T, T)>
+where
+    T: Float + Default,
+{
+    let mut candidates = vec![];
+    let complete = search(
+        (one, (T::zero(), T::one())),
+        (other, (T::zero(), T::one())),
+        tolerance,
+        0,
+        &mut candidates,
+    );
+    if !complete {
+        return overlap(one, other, tolerance);
+    }
+    let mut candidates = candidates
+        .into_iter()
+        .map(|candidate| refine(one, other, candidate))
+        .collect::<Vec<_>>();
+    // Endpoints go first, as they are exact.
+    let is_end = |(s, t): (T, T)| (s.is_zero() || s == T::one()) && (t.is_zero() || t == T::one());
+    candidates.sort_by(|&one, &other| {
+        (!is_end(one), one.0)
+            .partial_cmp(&(!is_end(other), other.0))
+            .unwrap_or(Ordering::Equal)
+    });
+    // Candidates are merged when the segments stay together in between, which is the case for
+    // tangential contacts.
+    let mut result: Vec<(T, T)> = vec![];
+    let half = T::from(0.5).unwrap();
+    for (s, t) in candidates {
+        let close = result.iter().any(|&(u, _)| {
+            distance(one.evaluate(u), one.evaluate(s)) <= tolerance
+                || other.project(one.evaluate((u + s) * half)).distance <= tolerance
+        });
+        if !close {
+            result.push((s, t));
+        }
+    }
+    result
+}
+
+/// Find the parameters at which a segment intersects itself.
+///
+/// Only cubic curves can intersect themselves, which they do at most once. Writing the curve as
+/// `a t^3 + b t^2 + c t + d`, the parameters `s` and `t` of a loop satisfy
+/// `a (s^2 + s t + t^2) + b (s + t) + c = 0`, which is linear in `s t` given `s + t`.
+pub fn find_loop<T: Float>(segment: &Segment<T>) -> Option<(T, T)> {
+    let (x, y) = match segment {
+        Segment::Cubic(x, y) => (x.polynomial(), y.polynomial()),
+        _ => return None,
+    };
+    let (a, b, c) = ((x[3], y[3]), (x[2], y[2]), (x[1], y[1]));
+    let cross = |one: (T, T), other: (T, T)| one.0 * other.1 - one.1 * other.0;
+    let dot = |one: (T, T), other: (T, T)| one.0 * other.0 + one.1 * other.1;
+    let denominator = cross(a, b);
+    if denominator.is_zero() {
+        return None;
+    }
+    let sum = -cross(a, c) / denominator;
+    let rest = (b.0 * sum + c.0, b.1 * sum + c.1);
+    let product = sum * sum + dot(rest, a) / dot(a, a);
+    let discriminant = sum * sum - (product + product + product + product);
+    if discriminant.is_nan() || discriminant <= T::zero() {
+        return None;
+    }
+    let half = T::from(0.5).unwrap();
+    let root = discriminant.sqrt();
+    let (s, t) = ((sum - root) * half, (sum + root) * half);
+    if s > T::zero() && t < T::one() {
+        Some((s, t))
+    } else {
+        None
+    }
+}
+
+fn search<T>(
+    (one, (a, b)): (&Segment<T>, (T, T)),
+    (other, (c, d)): (&Segment<T>, (T, T)),
+    tolerance: T,
+    depth: usize,
+    candidates: &mut Vec<(T, T)>,
+) -> bool
+where
+    T: Float + Default,
+{
+    let (first, second) = (one.bounds(), other.bounds());
+    if first.0 .0 > second.1 .0 + tolerance
+        || second.0 .0 > first.1 .0 + tolerance
+        || first.0 .1 > second.1 .1 + tolerance
+        || second.0 .1 > first.1 .1 + tolerance
+    {
+        return true;
+    }
+    let (one_size, other_size) = (size(first), size(second));
+    let two = T::one() + T::one();
+    if depth >= DEPTH || (one_size <= tolerance && other_size <= tolerance) {
+        let candidate = ((a + b) / two, (c + d) / two);
+        // Shared endpoints are taken exactly, as they would otherwise produce slivers.
+        let (one, other) = (
+            [(a, one.start()), (b, one.end())],
+            [(c, other.start()), (d, other.end())],
+        );
+        let endpoints = one
+            .into_iter()
+            .filter(|(s, _)| s.is_zero() || *s == T::one())
+            .flat_map(|one| other.into_iter().map(move |other| (one, other)))
+            .filter(|(_, (t, _))| t.is_zero() || *t == T::one())
+            .find(|((_, one), (_, other))| distance(*one, *other) <= tolerance)
+            .map(|((s, _), (t, _))| (s, t));
+        candidates.push(endpoints.unwrap_or(candidate));
+        return candidates.len() <= LIMIT;
+    }
+    let half = T::from(0.5).unwrap();
+    if one_size >= other_size {
+        let (head, tail) = one.subdivide(half);
+        let middle = (a + b) / two;
+        search(
+            (&head, (a, middle)),
+            (other, (c, d)),
+            tolerance,
+            depth + 1,
+            candidates,
+        ) && search(
+            (&tail, (middle, b)),
+            (other, (c, d)),
+            tolerance,
+            depth + 1,
+            candidates,
+        )
+    } else {
+        let (head, tail) = other.subdivide(half);
+        let middle = (c + d) / two;
+        search(
+            (one, (a, b)),
+            (&head, (c, middle)),
+            tolerance,
+            depth + 1,
+            candidates,
+        ) && search(
+            (one, (a, b)),
+            (&tail, (middle, d)),
+            tolerance,
+            depth + 1,
+            candidates,
+        )
+    }
+}
+
+// Newton's method is applied to the difference between the two segments.
+fn refine<T>(one: &Segment<T>, other: &Segment<T>, (mut s, mut t): (T, T)) -> (T, T)
+where
+    T: Float,
+{
+    for _ in 0..ITERATIONS {
+        let ((x, y), (u, v)) = (one.evaluate(s), other.evaluate(t));
+        let (dx, dy) = (x - u, y - v);
+        let ((a, b), (c, d)) = (derive(one, s), derive(other, t));
+        let determinant = c * b - a * d;
+        if determinant.is_zero() || !determinant.is_finite() {
+            break;
+        }
+        let (ds, dt) = (
+            (c * dy - d * dx) / determinant,
+            (a * dy - b * dx) / determinant,
+        );
+        let (next_s, next_t) = (s - ds, t - dt);
+        if !(T::zero() <= next_s && next_s <= T::one() && T::zero() <= next_t && next_t <= T::one())
+        {
+            break;
+        }
+        (s, t) = (next_s, next_t);
+    }
+    (s, t)
+}
+
+fn overlap<T>(one: &Segment<T>, other: &Segment<T>, tolerance: T) -> Vec<(T, T)>
+where
+    T: Float,
+{
+    let ends = |segment: &Segment<T>| [(T::zero(), segment.start()), (T::one(), segment.end())];
+    let mut result = vec![];
+    for (s, point) in ends(one) {
+        for (t, other) in ends(other) {
+            if distance(point, other) <= tolerance {
+                result.push((s, t));
+            }
+        }
+    }
+    for (t, point) in ends(other) {
+        let projection = one.project(point);
+        if projection.distance <= tolerance && !result.iter().any(|&(_, u)| u == t) {
+            result.push((projection.parameter, t));
+        }
+    }
+    for (s, point) in ends(one) {
+        let projection = other.project(point);
+        if projection.distance <= tolerance && !result.iter().any(|&(u, _)| u == s) {
+            result.push((s, projection.parameter));
+        }
+    }
+    result
+}
+
+#[inline]
+pub fn distance<T: Float>(one: (T, T), other: (T, T)) -> T {
+    (one.0 - other.0).hypot(one.1 - other.1)
+}
+
+fn derive<T: Float>(segment: &Segment<T>, t: T) -> (T, T) {
+    match *segment {
+        Segment::Linear(x, y) => (x, y).derive().evaluate(t),
+        Segment::Quadratic(x, y) => (x, y).derive().evaluate(t),
+        Segment::Cubic(x, y) => (x, y).derive().evaluate(t),
+    }
+}
+
+#[inline]
+fn size<T: Float>(((x0, y0), (x1, y1)): ((T, T), (T, T))) -> T {
+    (x1 - x0).max(y1 - y0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find, find_loop};
+    use crate::bezier::{Cubic, Linear};
+    use crate::evaluate::Evaluate;
+    use crate::path::Segment;
+
+    #[test]
+    fn cross() {
+        let one = Segment::Linear(Linear::new(0.0, 4.0), Linear::new(0.0, 4.0));
+        let other = Segment::Linear(Linear::new(0.0, 4.0), Linear::new(4.0, 0.0));
+        let result = find(&one, &other, 1e-9);
+        assert_eq!(result.len(), 1);
+        assert::close(&[result[0].0, result[0].1][..], &[0.5, 0.5][..], 1e-8);
+    }
+
+    #[test]
+    fn cubic() {
+        let one = Segment::Cubic(
+            Cubic::new(0.0, 0.0, 90.0, 100.0),
+            Cubic::new(0.0, 50.0, 0.0, 0.0),
+        );
+        let other = Segment::Linear(Linear::new(0.0, 100.0), Linear::new(10.0, 10.0));
+        assert_eq!(find(&one, &other, 1e-9).len(), 2);
+    }
+
+    #[test]
+    fn looping() {
+        let segment = Segment::Cubic(
+            Cubic::new(0.0, 3.0, -1.0, 2.0),
+            Cubic::new(0.0, 3.0, 3.0, 0.0),
+        );
+        let (s, t) = find_loop(&segment).unwrap();
+        assert!(s < t);
+        let (one, other) = (segment.evaluate(s), segment.evaluate(t));
+        assert::close(one.0, other.0, 1e-12);
+        assert::close(one.1, other.1, 1e-12);
+        let segment = Segment::Cubic(
+            Cubic::new(0.0, 1.0, 2.0, 3.0),
+            Cubic::new(0.0, 1.0, 1.0, 0.0),
+        );
+        assert_eq!(find_loop(&segment), None);
+    }
+
+    #[test]
+    fn overlap() {
+        let one = Segment::Linear(Linear::new(0.0, 4.0), Linear::new(0.0, 0.0));
+        let other = Segment::Linear(Linear::new(1.0, 6.0), Linear::new(0.0, 0.0));
+        let mut result = find(&one, &other, 1e-9);
+        result.sort_by(|one, other| one.partial_cmp(other).unwrap());
+        assert_eq!(result, vec![(0.25, 0.0), (1.0, 0.6)]);
+    }
+}
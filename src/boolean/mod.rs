@@ -0,0 +1,513 @@
+//! Boolean operations.
+
+mod intersection;
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, Result as FormatResult};
+
+use num_traits::Float;
+
+use crate::evaluate::Evaluate;
+use crate::frame::Frame;
+use crate::path::{Contour, Path, Segment};
+use crate::reverse::Reverse;
+use crate::subdivide::Subdivide;
+use crate::winding::{Fill, Winding};
+
+use intersection::distance;
+
+/// An operation.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Operation {
+    /// The points inside either path.
+    Union,
+    /// The points inside both paths.
+    Intersection,
+    /// The points inside the first path but not the second.
+    Difference,
+    /// The points inside exactly one path.
+    Xor,
+}
+
+/// An error.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// A coordinate that is not finite.
+    Infinite,
+    /// A fragment whose sides could not be told apart.
+    Unclassified,
+    /// A chain of fragments that could not be closed.
+    Unclosed,
+}
+
+impl Display for Error {
+    fn fmt(&self, formatter: &mut Formatter) -> FormatResult {
+        match self {
+            Self::Infinite => formatter.write_str("found a coordinate that is not finite"),
+            Self::Unclassified => formatter.write_str("failed to classify a fragment"),
+            Self::Unclosed => formatter.write_str("failed to close a contour"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A boolean operation on paths.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Boolean<T> {
+    operation: Operation,
+    fill: Fill,
+    tolerance: T,
+}
+
+impl<T: Float + Default> Boolean<T> {
+    /// Create an instance.
+    ///
+    /// The fill rule determines the insides of the input paths. The tolerance dictates the
+    /// distance below which points are considered coincident. It is raised to the resolution of
+    /// floating-point numbers at the magnitude of the coordinates if it is smaller.
+    #[inline]
+    pub fn new(operation: Operation, fill: Fill, tolerance: T) -> Self {
+        Self {
+            operation,
+            fill,
+            tolerance,
+        }
+    }
+
+    /// Perform the operation.
+    ///
+    /// The segments are split at their intersections, and the resulting fragments that separate
+    /// the inside of the result from the outside are connected into closed contours. The
+    /// fragments keep the kinds of the original segments, and they are oriented so that the
+    /// inside is on the left-hand side, which makes outer contours counterclockwise when the
+    /// y-axis points up.
+    ///
+    /// An error is returned if a coordinate is not finite, if the inside of a fragment cannot be
+    /// determined, or if the fragments do not form closed contours, which can happen when the
+    /// input is degenerate.
+    pub fn apply(&self, one: &Path<T>, other: &Path<T>) -> Result<Path<T>, Error> {
+        if !one.segments().chain(other.segments()).all(is_finite) {
+            return Err(Error::Infinite);
+        }
+        let this = Self {
+            tolerance: self.tolerance.max(resolution(one, other)),
+            ..*self
+        };
+        this.perform(one, other)
+    }
+
+    fn perform(&self, one: &Path<T>, other: &Path<T>) -> Result<Path<T>, Error> {
+        let segments = one
+            .segments()
+            .chain(other.segments())
+            .copied()
+            .chain(one.iter().filter_map(Contour::closing))
+            .chain(other.iter().filter_map(Contour::closing))
+            .filter(|segment| !is_degenerate(segment, self.tolerance))
+            .collect::<Vec<_>>();
+        let mut parameters = vec![vec![]; segments.len()];
+        for i in 0..segments.len() {
+            if let Some((s, t)) = intersection::find_loop(&segments[i]) {
+                parameters[i].push(s);
+                parameters[i].push(t);
+            }
+            for j in (i + 1)..segments.len() {
+                for (s, t) in intersection::find(&segments[i], &segments[j], self.tolerance) {
+                    parameters[i].push(s);
+                    parameters[j].push(t);
+                }
+            }
+        }
+        let mut vertices = vec![];
+        let mut fragments = vec![];
+        for (segment, mut parameters) in segments.into_iter().zip(parameters) {
+            parameters.sort_by(|one, other| one.partial_cmp(other).unwrap_or(Ordering::Equal));
+            for fragment in self.split(segment, &parameters) {
+                let start = snap(&mut vertices, fragment.start(), self.tolerance);
+                let end = snap(&mut vertices, fragment.end(), self.tolerance);
+                if start == end && is_degenerate(&fragment, self.tolerance) {
+                    continue;
+                }
+                match self.classify(&fragment, one, other)? {
+                    Some(true) => fragments.push((start, end, fragment)),
+                    Some(false) => fragments.push((end, start, fragment.reverse())),
+                    None => {}
+                }
+            }
+        }
+        self.assemble(&vertices, fragments)
+    }
+
+    fn split(&self, segment: Segment<T>, parameters: &[T]) -> Vec<Segment<T>> {
+        let mut fragments = vec![];
+        let (mut rest, mut last) = (segment, T::zero());
+        for &t in parameters {
+            // The parts are compared instead of the points, since a loop ends where it starts.
+            let (head, tail) = rest.subdivide((t - last) / (T::one() - last));
+            if is_degenerate(&head, self.tolerance) || is_degenerate(&tail, self.tolerance) {
+                continue;
+            }
+            fragments.push(head);
+            (rest, last) = (tail, t);
+        }
+        fragments.push(rest);
+        fragments
+    }
+
+    // Return if the inside is on the left-hand side or nothing if the fragment is not a boundary.
+    // The fragment is probed on both sides at several points in case the probes at one of them
+    // cannot be told apart.
+    fn classify(
+        &self,
+        fragment: &Segment<T>,
+        one: &Path<T>,
+        other: &Path<T>,
+    ) -> Result<Option<bool>, Error> {
+        for t in [0.5, 0.25, 0.75] {
+            let t = T::from(t).unwrap();
+            let (x, y) = fragment.evaluate(t);
+            let (u, v) = fragment.normal(t);
+            let left = (x + self.tolerance * u, y + self.tolerance * v);
+            let right = (x - self.tolerance * u, y - self.tolerance * v);
+            if !(u.is_finite() && v.is_finite()) || left == right {
+                continue;
+            }
+            return Ok(
+                match (
+                    self.contains(left, one, other),
+                    self.contains(right, one, other),
+                ) {
+                    (true, false) => Some(true),
+                    (false, true) => Some(false),
+                    _ => None,
+                },
+            );
+        }
+        Err(Error::Unclassified)
+    }
+
+    fn contains(&self, point: (T, T), one: &Path<T>, other: &Path<T>) -> bool {
+        let (one, other) = (
+            one.encloses(point, self.fill),
+            other.encloses(point, self.fill),
+        );
+        match self.operation {
+            Operation::Union => one || other,
+            Operation::Intersection => one && other,
+            Operation::Difference => one && !other,
+            Operation::Xor => one != other,
+        }
+    }
+
+    fn assemble(
+        &self,
+        vertices: &[(T, T)],
+        fragments: Vec<(usize, usize, Segment<T>)>,
+    ) -> Result<Path<T>, Error> {
+        let mut unique: Vec<(usize, usize, Segment<T>)> = vec![];
+        let half = T::from(0.5).unwrap();
+        for fragment in fragments {
+            let duplicate = unique.iter().any(|other| {
+                other.0 == fragment.0
+                    && other.1 == fragment.1
+                    && distance(other.2.evaluate(half), fragment.2.evaluate(half)) <= self.tolerance
+            });
+            if !duplicate {
+                unique.push(fragment);
+            }
+        }
+        let mut used = vec![false; unique.len()];
+        let mut path = Path::new();
+        for first in 0..unique.len() {
+            if used[first] {
+                continue;
+            }
+            let mut chain = vec![first];
+            used[first] = true;
+            let mut closed = false;
+            loop {
+                let (_, end, ref current) = unique[*chain.last().unwrap()];
+                if end == unique[first].0 {
+                    closed = true;
+                    break;
+                }
+                // Taking the leftmost turn keeps contours touching at a point apart.
+                let incoming = current.tangent(T::one());
+                let next = (0..unique.len())
+                    .filter(|&i| !used[i] && unique[i].0 == end)
+                    .max_by(|&i, &j| {
+                        let one = turn(incoming, unique[i].2.tangent(T::zero()));
+                        let other = turn(incoming, unique[j].2.tangent(T::zero()));
+                        one.partial_cmp(&other).unwrap_or(Ordering::Equal)
+                    });
+                match next {
+                    Some(next) => {
+                        used[next] = true;
+                        chain.push(next);
+                    }
+                    None => break,
+                }
+            }
+            if !closed {
+                return Err(Error::Unclosed);
+            }
+            path.move_to(vertices[unique[first].0]);
+            for i in chain {
+                let (start, end, segment) = unique[i];
+                path.push(attach(segment, vertices[start], vertices[end]));
+            }
+            path.close();
+        }
+        Ok(path)
+    }
+}
+
+fn attach<T: Float>(segment: Segment<T>, start: (T, T), end: (T, T)) -> Segment<T> {
+    match segment {
+        Segment::Linear(mut x, mut y) => {
+            (x[0], y[0], x[1], y[1]) = (start.0, start.1, end.0, end.1);
+            Segment::Linear(x, y)
+        }
+        Segment::Quadratic(mut x, mut y) => {
+            (x[0], y[0], x[2], y[2]) = (start.0, start.1, end.0, end.1);
+            Segment::Quadratic(x, y)
+        }
+        Segment::Cubic(mut x, mut y) => {
+            (x[0], y[0], x[3], y[3]) = (start.0, start.1, end.0, end.1);
+            Segment::Cubic(x, y)
+        }
+    }
+}
+
+fn is_finite<T: Float>(segment: &Segment<T>) -> bool {
+    let (x, y): (&[T], &[T]) = match segment {
+        Segment::Linear(x, y) => (&x[..], &y[..]),
+        Segment::Quadratic(x, y) => (&x[..], &y[..]),
+        Segment::Cubic(x, y) => (&x[..], &y[..]),
+    };
+    x.iter().chain(y).all(|value| value.is_finite())
+}
+
+fn is_degenerate<T: Float>(segment: &Segment<T>, tolerance: T) -> bool {
+    let ((x0, y0), (x1, y1)) = segment.bounds();
+    (x1 - x0).max(y1 - y0) <= tolerance
+}
+
+// The distance below which coordinates of the given magnitude cannot be reliably distinguished
+// after a few arithmetic operations.
+fn resolution<T: Float>(one: &Path<T>, other: &Path<T>) -> T {
+    let magnitude = one
+        .segments()
+        .chain(other.segments())
+        .map(|segment| {
+            let ((x0, y0), (x1, y1)) = segment.bounds();
+            x0.abs().max(y0.abs()).max(x1.abs()).max(y1.abs())
+        })
+        .fold(T::zero(), T::max);
+    magnitude * T::epsilon() * T::from(64).unwrap()
+}
+
+fn snap<T: Float>(vertices: &mut Vec<(T, T)>, point: (T, T), tolerance: T) -> usize {
+    match vertices
+        .iter()
+        .position(|vertex| distance(*vertex, point) <= tolerance)
+    {
+        Some(index) => index,
+        None => {
+            vertices.push(point);
+            vertices.len() - 1
+        }
+    }
+}
+
+// The signed angle from one direction to another, positive for counterclockwise turns.
+fn turn<T: Float>(one: (T, T), other: (T, T)) -> T {
+    let cross = one.0 * other.1 - one.1 * other.0;
+    let dot = one.0 * other.0 + one.1 * other.1;
+    cross.atan2(dot)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::area::Area;
+    use crate::boolean::{Boolean, Error, Operation};
+    use crate::path::{Path, Segment};
+    use crate::winding::Fill;
+
+    #[test]
+    fn squares() {
+        let one = square((0.0, 0.0), 2.0);
+        let other = square((1.0, 1.0), 2.0);
+        for (operation, area, count) in [
+            (Operation::Union, 7.0, 1),
+            (Operation::Intersection, 1.0, 1),
+            (Operation::Difference, 3.0, 1),
+            (Operation::Xor, 6.0, 2),
+        ] {
+            let result = Boolean::new(operation, Fill::NonZero, 1e-9)
+                .apply(&one, &other)
+                .unwrap();
+            assert::close(result.area(), area, 1e-9);
+            assert_eq!(result.len(), count);
+            assert!(result.iter().all(|contour| contour.is_closed()));
+        }
+    }
+
+    #[test]
+    fn adjacent() {
+        let one = square((0.0, 0.0), 2.0);
+        let other = square((2.0, 0.0), 2.0);
+        let result = Boolean::new(Operation::Union, Fill::NonZero, 1e-9)
+            .apply(&one, &other)
+            .unwrap();
+        assert::close(result.area(), 8.0, 1e-9);
+        assert_eq!(result.len(), 1);
+        let result = Boolean::new(Operation::Union, Fill::NonZero, 1e-9)
+            .apply(&one, &one)
+            .unwrap();
+        assert::close(result.area(), 4.0, 1e-9);
+        let result = Boolean::new(Operation::Intersection, Fill::NonZero, 1e-9)
+            .apply(&one, &other)
+            .unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn curves() {
+        let one = circle((0.0, 0.0), 1.0);
+        let other = circle((1.0, 0.0), 1.0);
+        let area = one.area();
+        let lens = Boolean::new(Operation::Intersection, Fill::NonZero, 1e-9)
+            .apply(&one, &other)
+            .unwrap();
+        let union = Boolean::new(Operation::Union, Fill::NonZero, 1e-9)
+            .apply(&one, &other)
+            .unwrap();
+        assert::close(union.area() + lens.area(), 2.0 * area, 1e-9);
+        let expected = 2.0 * std::f64::consts::PI / 3.0 - 3.0f64.sqrt() / 2.0;
+        assert::close(lens.area(), expected, 1e-2);
+        assert!(union
+            .segments()
+            .all(|segment| matches!(segment, Segment::Quadratic(..))));
+
+        let hole = circle((0.0, 0.0), 0.5);
+        let ring = Boolean::new(Operation::Difference, Fill::NonZero, 1e-9)
+            .apply(&one, &hole)
+            .unwrap();
+        assert_eq!(ring.len(), 2);
+        assert::close(ring.area(), area - hole.area(), 1e-9);
+    }
+
+    #[test]
+    fn mixed() {
+        let k = 0.5522847498307936;
+        let mut circle = Path::new();
+        circle.move_to((1.0, 0.0));
+        circle.cubic_to((1.0, k), (k, 1.0), (0.0, 1.0));
+        circle.cubic_to((-k, 1.0), (-1.0, k), (-1.0, 0.0));
+        circle.cubic_to((-1.0, -k), (-k, -1.0), (0.0, -1.0));
+        circle.cubic_to((k, -1.0), (1.0, -k), (1.0, 0.0));
+        circle.close();
+        let square = square((0.0, 0.0), 2.0);
+        let result = Boolean::new(Operation::Difference, Fill::NonZero, 1e-9)
+            .apply(&square, &circle)
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert::close(result.area(), 4.0 - circle.area() / 4.0, 1e-9);
+        assert_eq!(
+            result
+                .segments()
+                .filter(|segment| matches!(segment, Segment::Cubic(..)))
+                .count(),
+            1,
+        );
+    }
+
+    #[test]
+    fn touching() {
+        let one = square((0.0, 0.0), 1.0);
+        let other = square((1.0, 1.0), 1.0);
+        let result = Boolean::new(Operation::Union, Fill::NonZero, 1e-9)
+            .apply(&one, &other)
+            .unwrap();
+        assert_eq!(result.len(), 2);
+        assert::close(result.area(), 2.0, 1e-9);
+        assert!(result.iter().all(|contour| contour.len() == 4));
+    }
+
+    #[test]
+    fn precision() {
+        let mut path = Path::new();
+        path.move_to((1000.0f32, 1000.0));
+        path.line_to((1200.0, 1200.0));
+        path.line_to((1200.0, 1000.0));
+        path.line_to((1000.0, 1200.0));
+        path.close();
+        let result = Boolean::new(Operation::Union, Fill::NonZero, 1e-4)
+            .apply(&path, &Path::new())
+            .unwrap();
+        assert_eq!(result.len(), 2);
+        assert::close(result.area(), 20000.0, 1.0);
+
+        let mut path = Path::new();
+        path.move_to((1e6, 1e6));
+        path.line_to((1e6 + 2.0, 1e6 + 2.0));
+        path.line_to((1e6 + 2.0, 1e6));
+        path.line_to((1e6, 1e6 + 2.0));
+        path.close();
+        let result = Boolean::new(Operation::Union, Fill::NonZero, 1e-12)
+            .apply(&path, &Path::new())
+            .unwrap();
+        assert_eq!(result.len(), 2);
+        assert::close(result.area(), 2.0, 1e-6);
+    }
+
+    #[test]
+    fn invalid() {
+        let mut path = square((0.0, 0.0), 2.0);
+        path.move_to((f64::NAN, 0.0));
+        path.line_to((1.0, f64::NAN));
+        path.line_to((1.0, 1.0));
+        path.close();
+        let boolean = Boolean::new(Operation::Union, Fill::NonZero, 1e-9);
+        assert_eq!(boolean.apply(&path, &path), Err(Error::Infinite));
+        let mut path = square((0.0, 0.0), 2.0);
+        path.line_to((f64::INFINITY, 1.0));
+        assert_eq!(
+            boolean.apply(&square((0.0, 0.0), 1.0), &path),
+            Err(Error::Infinite)
+        );
+    }
+
+    fn circle(center: (f64, f64), radius: f64) -> Path<f64> {
+        let (k, s) = (2.0f64.sqrt() - 1.0, std::f64::consts::FRAC_1_SQRT_2);
+        let octants = [((1.0, k), (s, s)), ((k, 1.0), (0.0, 1.0))];
+        let point = |(x, y): (f64, f64)| (center.0 + radius * x, center.1 + radius * y);
+        let mut path = Path::new();
+        path.move_to(point((1.0, 0.0)));
+        for i in 0..4 {
+            let rotate = |(x, y): (f64, f64)| match i {
+                0 => (x, y),
+                1 => (-y, x),
+                2 => (-x, -y),
+                _ => (y, -x),
+            };
+            for (control, end) in octants {
+                path.quad_to(point(rotate(control)), point(rotate(end)));
+            }
+        }
+        path.close();
+        path
+    }
+
+    fn square(corner: (f64, f64), size: f64) -> Path<f64> {
+        let mut path = Path::new();
+        path.move_to(corner);
+        path.line_to((corner.0 + size, corner.1));
+        path.line_to((corner.0 + size, corner.1 + size));
+        path.line_to((corner.0, corner.1 + size));
+        path.close();
+        path
+    }
+}
@@ -0,0 +1,212 @@
+//! Continuity.
+
+use num_traits::Float;
+
+use crate::derive::Derive;
+use crate::evaluate::Evaluate;
+use crate::frame::Frame;
+use crate::path::{Contour, Segment};
+
+/// A kind of continuity.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Kind {
+    /// The curves meet.
+    C0,
+    /// The curves meet, and their first derivatives coincide.
+    C1,
+    /// The curves meet, and their tangents coincide.
+    G1,
+    /// The curves meet, and their first and second derivatives coincide.
+    C2,
+    /// The curves meet, and their tangents and curvatures coincide.
+    G2,
+}
+
+/// A measurement of continuity at the joint of two consecutive curves.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Continuity<T> {
+    /// The distance between the end of the first curve and the start of the second.
+    pub gap: T,
+    /// The signed angle from the ending tangent of the first curve to the starting tangent of
+    /// the second in radians.
+    pub angle: T,
+    /// The norm of the difference between the first derivatives.
+    pub velocity: T,
+    /// The norm of the difference between the second derivatives.
+    pub acceleration: T,
+    /// The difference between the signed curvatures, the second minus the first.
+    pub curvature: T,
+}
+
+impl<T: Float> Continuity<T> {
+    /// Create an instance.
+    pub fn new<U, V>(one: U, other: V) -> Self
+    where
+        U: Into<Segment<T>>,
+        V: Into<Segment<T>>,
+    {
+        let (one, other) = (one.into(), other.into());
+        let (zero, unit) = (T::zero(), T::one());
+        let ([point, first, second], [start, third, fourth]) =
+            (derivatives(&one, unit), derivatives(&other, zero));
+        let (tangent, next) = (one.tangent(unit), other.tangent(zero));
+        let cross = tangent.0 * next.1 - tangent.1 * next.0;
+        let dot = tangent.0 * next.0 + tangent.1 * next.1;
+        Self {
+            gap: distance(point, start),
+            angle: cross.atan2(dot),
+            velocity: distance(first, third),
+            acceleration: distance(second, fourth),
+            curvature: other.curvature(zero) - one.curvature(unit),
+        }
+    }
+
+    /// Check if a kind of continuity holds within a tolerance.
+    ///
+    /// The tolerance is used for all measurements involved, including the angle in radians.
+    pub fn is(&self, kind: Kind, tolerance: T) -> bool {
+        match kind {
+            Kind::C0 => self.gap <= tolerance,
+            Kind::C1 => self.is(Kind::C0, tolerance) && self.velocity <= tolerance,
+            Kind::G1 => self.is(Kind::C0, tolerance) && self.angle.abs() <= tolerance,
+            Kind::C2 => self.is(Kind::C1, tolerance) && self.acceleration <= tolerance,
+            Kind::G2 => self.is(Kind::G1, tolerance) && self.curvature.abs() <= tolerance,
+        }
+    }
+}
+
+impl<T: Float> Contour<T> {
+    /// Measure continuity at each joint of consecutive segments.
+    ///
+    /// For closed contours, the joint between the last and the first segment is included.
+    pub fn continuity(&self) -> Vec<Continuity<T>> {
+        let mut joints = self
+            .windows(2)
+            .map(|pair| Continuity::new(pair[0], pair[1]))
+            .collect::<Vec<_>>();
+        if self.is_closed() && self.len() > 1 {
+            joints.push(Continuity::new(self[self.len() - 1], self[0]));
+        }
+        joints
+    }
+}
+
+fn derivatives<T: Float>(segment: &Segment<T>, t: T) -> [(T, T); 3] {
+    match *segment {
+        Segment::Linear(x, y) => {
+            let first = (x, y).derive();
+            let second = first.derive();
+            [(x, y).evaluate(t), first.evaluate(t), second.evaluate(t)]
+        }
+        Segment::Quadratic(x, y) => {
+            let first = (x, y).derive();
+            let second = first.derive();
+            [(x, y).evaluate(t), first.evaluate(t), second.evaluate(t)]
+        }
+        Segment::Cubic(x, y) => {
+            let first = (x, y).derive();
+            let second = first.derive();
+            [(x, y).evaluate(t), first.evaluate(t), second.evaluate(t)]
+        }
+    }
+}
+
+fn distance<T: Float>(one: (T, T), other: (T, T)) -> T {
+    (one.0 - other.0).hypot(one.1 - other.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use crate::approximation::Approximation;
+    use crate::arc::goodness::SampledRadialAbsolute;
+    use crate::arc::Arc;
+    use crate::bezier::goodness::CrudeIndependentAbsolute;
+    use crate::bezier::{Cubic, Linear, Quadratic};
+    use crate::continuity::{Continuity, Kind};
+    use crate::conversion::Conversion;
+    use crate::path::Path;
+    use crate::subdivide::Subdivide;
+
+    #[test]
+    fn subdivide() {
+        let curve = (
+            Cubic::new(0.0, 0.0, 90.0, 100.0),
+            Cubic::new(0.0, 50.0, 0.0, 0.0),
+        );
+        let (head, tail) = curve.subdivide(0.25);
+        let continuity = Continuity::new(head, tail);
+        assert!(continuity.is(Kind::G1, 1e-12));
+        assert!(continuity.is(Kind::G2, 1e-12));
+        assert!(!continuity.is(Kind::C1, 1e-12));
+    }
+
+    #[test]
+    fn approximate() {
+        let curve = (
+            Cubic::new(0.0, 0.0, 90.0, 100.0),
+            Cubic::new(0.0, 50.0, 0.0, 0.0),
+        );
+        let goodness = CrudeIndependentAbsolute::new(1.0, f64::MAX, usize::MAX);
+        let quadratics: Vec<(Quadratic<f64>, Quadratic<f64>)> =
+            Approximation::new(curve, goodness).collect();
+        for pair in quadratics.windows(2) {
+            let continuity = Continuity::new(pair[0], pair[1]);
+            assert!(continuity.is(Kind::G1, 1e-12));
+        }
+
+        // Polylines have corners.
+        let curve = (
+            Quadratic::new(0.0, 50.0, 100.0),
+            Quadratic::new(0.0, 100.0, 0.0),
+        );
+        let goodness = CrudeIndependentAbsolute::new(5.0, f64::MAX, usize::MAX);
+        let lines: Vec<(Linear<f64>, Linear<f64>)> = Approximation::new(curve, goodness).collect();
+        assert!(lines.len() > 1);
+        for pair in lines.windows(2) {
+            let continuity = Continuity::new(pair[0], pair[1]);
+            assert!(continuity.is(Kind::C0, 1e-12));
+            assert!(!continuity.is(Kind::G1, 1e-3));
+        }
+
+        // The conversion of arcs preserves tangents too.
+        let arc = Arc::new((10.0, 20.0), (200.0, 100.0), FRAC_PI_2, 0.0, -PI);
+        let goodness = SampledRadialAbsolute::new(0.1, f64::MAX, usize::MAX);
+        let quadratics: Vec<(Quadratic<f64>, Quadratic<f64>)> =
+            Conversion::new(arc, goodness).collect();
+        assert!(quadratics.len() > 1);
+        for pair in quadratics.windows(2) {
+            let continuity = Continuity::new(pair[0], pair[1]);
+            assert!(continuity.is(Kind::G1, 1e-9));
+        }
+    }
+
+    #[test]
+    fn corner() {
+        let one = (Linear::new(0.0, 1.0), Linear::new(0.0, 0.0));
+        let other = (Linear::new(1.0, 1.0), Linear::new(0.0, 2.0));
+        let continuity = Continuity::new(one, other);
+        assert!(continuity.is(Kind::C0, 0.0));
+        assert!(!continuity.is(Kind::G1, 1e-3));
+        assert::close(continuity.angle, std::f64::consts::FRAC_PI_2, 1e-12);
+        assert::close(continuity.velocity, 5.0f64.sqrt(), 1e-12);
+        assert_eq!(continuity.curvature, 0.0);
+    }
+
+    #[test]
+    fn contour() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.line_to((1.0, 0.0));
+        path.line_to((2.0, 0.0));
+        path.quad_to((3.0, 0.0), (3.0, 1.0));
+        path.close();
+        let joints = path[0].continuity();
+        assert_eq!(joints.len(), 4);
+        assert!(joints[0].is(Kind::C2, 0.0));
+        assert!(joints[1].is(Kind::G1, 0.0));
+        assert!(!joints[1].is(Kind::G2, 1e-3));
+        assert!(!joints[2].is(Kind::G1, 1e-3));
+    }
+}
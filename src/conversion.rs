@@ -0,0 +1,73 @@
+use num_traits::Float;
+
+use crate::goodness::Goodness;
+use crate::subdivide::Subdivide;
+
+/// A conversion of a curve into a sequence of curves of another kind.
+///
+/// The goodness of fit is given pairs of a curve and its converted counterpart, where the
+/// candidate one is a part of the curve being converted, and the original one is the whole
+/// curve.
+pub struct Conversion<T, U, V, W> {
+    curves: Vec<U>,
+    original: (U, V),
+    goodness: W,
+    time: T,
+}
+
+impl<T, U, V, W> Conversion<T, U, V, W>
+where
+    T: Float,
+    U: Clone + Into<V>,
+{
+    /// Create an instance.
+    #[inline]
+    pub fn new(curve: U, goodness: W) -> Self {
+        Self::with_parts(vec![curve.clone()], curve, goodness)
+    }
+
+    /// Create an instance with the curve already split into consecutive parts.
+    pub(crate) fn with_parts(mut parts: Vec<U>, curve: U, goodness: W) -> Self {
+        let one = T::one();
+        parts.reverse();
+        Self {
+            curves: parts,
+            original: (curve.clone(), curve.into()),
+            goodness,
+            time: one / (one + one),
+        }
+    }
+
+    /// Return the parts of the curve that are left to convert in order, which are present if
+    /// the process has been stopped by the goodness of fit.
+    #[inline]
+    pub fn remainder(&self) -> impl Iterator<Item = &U> {
+        self.curves.iter().rev()
+    }
+}
+
+impl<T, U, V, W> Iterator for Conversion<T, U, V, W>
+where
+    T: Float,
+    U: Clone + Into<V> + Subdivide<T>,
+    W: Goodness<(U, V)>,
+{
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(curve) = self.curves.pop() {
+            let candidate = (curve.clone(), curve.into());
+            let Some(admitted) = self.goodness.admit(&candidate, &self.original) else {
+                self.curves.push(candidate.0);
+                return None;
+            };
+            if admitted {
+                return Some(candidate.1);
+            }
+            let (head, tail) = candidate.0.subdivide(self.time);
+            self.curves.push(tail);
+            self.curves.push(head);
+        }
+        None
+    }
+}
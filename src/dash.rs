@@ -0,0 +1,271 @@
+//! Dashes.
+
+use num_traits::Float;
+
+use crate::length::Length;
+use crate::path::{Path, Segment};
+use crate::subdivide::Subdivide;
+
+const LIMIT: usize = 1 << 16;
+
+/// A dash pattern.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dash<T> {
+    array: Vec<T>,
+    offset: T,
+}
+
+impl<T: Float + Default> Dash<T> {
+    /// Create an instance.
+    ///
+    /// The array alternates the lengths of dashes and gaps, and it is repeated twice if its length
+    /// is odd. The offset is the distance into the pattern at which dashing starts. A pattern that
+    /// is empty, has a negative length, or sums up to zero leaves curves intact, and so does one
+    /// that would cut a curve into more than 65536 pieces. Dashes of zero length are kept as
+    /// degenerate curves, which turn into dots when stroked with round caps.
+    pub fn new(array: Vec<T>, offset: T) -> Self {
+        let array = if array.len() % 2 == 1 {
+            array.iter().chain(array.iter()).copied().collect()
+        } else {
+            array
+        };
+        Self { array, offset }
+    }
+
+    /// Dash a curve.
+    pub fn split<U>(&self, curve: &U) -> Vec<U>
+    where
+        U: Clone + Length<T> + Subdivide<T>,
+    {
+        let total = curve.length(T::one());
+        match self.intervals(total) {
+            Some(intervals) => intervals
+                .into_iter()
+                .map(|(start, end)| extract(curve, start, end, total))
+                .collect(),
+            None => vec![curve.clone()],
+        }
+    }
+
+    /// Dash a path.
+    ///
+    /// Each dash becomes an open contour. For closed contours, the dashes meeting at the start
+    /// are joined together.
+    pub fn apply(&self, path: &Path<T>) -> Path<T> {
+        let mut dashed = Path::new();
+        for contour in path {
+            let lengths = contour
+                .iter()
+                .map(|segment| segment.length(T::one()))
+                .collect::<Vec<_>>();
+            let total = lengths.iter().fold(T::zero(), |sum, &length| sum + length);
+            let mut intervals = match self.intervals(total) {
+                Some(intervals) => intervals,
+                None => {
+                    dashed.move_to(contour.start());
+                    contour.iter().for_each(|segment| dashed.push(*segment));
+                    if contour.is_closed() {
+                        dashed.close();
+                    }
+                    continue;
+                }
+            };
+            let mut tail = None;
+            if contour.is_closed() && intervals.len() > 1 {
+                let (first, last) = (intervals[0], intervals[intervals.len() - 1]);
+                if first.0 == T::zero() && last.1 == total {
+                    tail = intervals.pop();
+                }
+            }
+            for (i, (start, end)) in intervals.into_iter().enumerate() {
+                let mut segments = vec![];
+                if i == 0 {
+                    if let Some((start, end)) = tail {
+                        segments.extend(pieces(contour, &lengths, start, end));
+                    }
+                }
+                segments.extend(pieces(contour, &lengths, start, end));
+                if let Some(segment) = segments.first() {
+                    dashed.move_to(segment.start());
+                }
+                segments
+                    .into_iter()
+                    .for_each(|segment| dashed.push(segment));
+            }
+        }
+        dashed
+    }
+
+    fn intervals(&self, total: T) -> Option<Vec<(T, T)>> {
+        if self.array.iter().any(|length| *length < T::zero()) {
+            return None;
+        }
+        let period = self
+            .array
+            .iter()
+            .fold(T::zero(), |sum, &length| sum + length);
+        if period <= T::zero() || !period.is_finite() {
+            return None;
+        }
+        let count = (total / period).ceil() + T::one();
+        if count * T::from(self.array.len()).unwrap() > T::from(LIMIT).unwrap() {
+            return None;
+        }
+        let mut offset = self.offset % period;
+        if offset < T::zero() {
+            offset = offset + period;
+        }
+        let mut index = 0;
+        while offset > T::zero() && offset >= self.array[index] {
+            offset = offset - self.array[index];
+            index = (index + 1) % self.array.len();
+        }
+        let mut intervals = vec![];
+        let (mut position, mut remaining) = (T::zero(), self.array[index] - offset);
+        while position < total {
+            let end = (position + remaining).min(total);
+            if index % 2 == 0 && (end > position || remaining.is_zero()) {
+                intervals.push((position, end));
+            }
+            position = end;
+            index = (index + 1) % self.array.len();
+            remaining = self.array[index];
+        }
+        Some(intervals)
+    }
+}
+
+fn extract<T, U>(curve: &U, start: T, end: T, total: T) -> U
+where
+    T: Float,
+    U: Clone + Length<T> + Subdivide<T>,
+{
+    let start = if start > T::zero() {
+        curve.parameter(start)
+    } else {
+        T::zero()
+    };
+    let end = if end < total {
+        curve.parameter(end)
+    } else {
+        T::one()
+    };
+    curve.extract(start, end)
+}
+
+fn pieces<T>(segments: &[Segment<T>], lengths: &[T], start: T, end: T) -> Vec<Segment<T>>
+where
+    T: Float + Default,
+{
+    let mut pieces = vec![];
+    let mut offset = T::zero();
+    for (segment, &length) in segments.iter().zip(lengths) {
+        let (lower, upper) = (start.max(offset), end.min(offset + length));
+        if lower < upper || (start == end && lower == upper) {
+            pieces.push(extract(segment, lower - offset, upper - offset, length));
+            if start == end {
+                break;
+            }
+        }
+        offset = offset + length;
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bezier::{Cubic, Linear};
+    use crate::dash::Dash;
+    use crate::length::Length;
+    use crate::path::Path;
+    use crate::stroke::{Cap, Join, Stroke};
+
+    #[test]
+    fn line() {
+        let curve = (Linear::new(0.0, 10.0), Linear::new(0.0, 0.0));
+        let dashes = Dash::new(vec![2.0, 1.0], 0.0).split(&curve);
+        assert_eq!(dashes.len(), 4);
+        assert::close(&dashes[0].0[..], &[0.0, 2.0][..], 1e-12);
+        assert::close(&dashes[1].0[..], &[3.0, 5.0][..], 1e-12);
+        assert::close(&dashes[3].0[..], &[9.0, 10.0][..], 1e-12);
+
+        let dashes = Dash::new(vec![2.0], 3.0).split(&curve);
+        assert_eq!(dashes.len(), 3);
+        assert::close(&dashes[0].0[..], &[1.0, 3.0][..], 1e-12);
+
+        let dashes = Dash::new(vec![], 0.0).split(&curve);
+        assert_eq!(dashes, vec![curve]);
+    }
+
+    #[test]
+    fn cubic() {
+        let curve = (
+            Cubic::new(0.0, 0.0, 90.0, 100.0),
+            Cubic::new(0.0, 50.0, 0.0, 0.0),
+        );
+        let dashes = Dash::new(vec![10.0, 5.0], 2.0).split(&curve);
+        assert::close(dashes[0].length(1.0), 8.0, 1e-9);
+        for dash in &dashes[1..dashes.len() - 1] {
+            assert::close(dash.length(1.0), 10.0, 1e-9);
+        }
+    }
+
+    #[test]
+    fn closed() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.line_to((4.0, 0.0));
+        path.line_to((4.0, 4.0));
+        path.line_to((0.0, 4.0));
+        path.close();
+        let dashed = Dash::new(vec![3.0, 2.0], 1.0).apply(&path);
+        assert_eq!(dashed.len(), 3);
+        close(dashed[0].start(), (0.0, 2.0));
+        assert_eq!(dashed[0].len(), 2);
+        close(dashed[0][1].end(), (2.0, 0.0));
+        close(dashed[1].start(), (4.0, 0.0));
+        assert_eq!(dashed[1].len(), 1);
+        close(dashed[2].start(), (3.0, 4.0));
+        close(dashed.position(), (0.0, 4.0));
+        assert!(dashed.iter().all(|contour| !contour.is_closed()));
+    }
+
+    #[test]
+    fn dots() {
+        let curve = (Linear::new(0.0, 5.0), Linear::new(0.0, 0.0));
+        let dashes = Dash::new(vec![0.0, 2.0], 0.0).split(&curve);
+        assert_eq!(dashes.len(), 3);
+        for (dash, x) in dashes.iter().zip([0.0, 2.0, 4.0]) {
+            assert::close(&dash.0[..], &[x, x][..], 1e-12);
+        }
+
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.line_to((2.0, 0.0));
+        path.line_to((2.0, 3.0));
+        let dashed = Dash::new(vec![0.0, 2.0], 0.0).apply(&path);
+        assert_eq!(dashed.len(), 3);
+        for (contour, point) in dashed.iter().zip([(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)]) {
+            assert_eq!(contour.len(), 1);
+            close(contour.start(), point);
+            close(contour[0].end(), point);
+        }
+        let outline = Stroke::new(1.0, Join::Round, Cap::Round, 0.01).outline(&dashed);
+        assert_eq!(outline.len(), 3);
+    }
+
+    #[test]
+    fn limit() {
+        let curve = (Linear::new(0.0, 1e6), Linear::new(0.0, 0.0));
+        let dashes = Dash::new(vec![1e-3, 1e-3], 0.0).split(&curve);
+        assert_eq!(dashes, vec![curve]);
+    }
+
+    fn close(point: (f64, f64), expected: (f64, f64)) {
+        assert::close(
+            &[point.0, point.1][..],
+            &[expected.0, expected.1][..],
+            1e-12,
+        );
+    }
+}
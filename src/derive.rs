@@ -0,0 +1,53 @@
+use num_traits::Float;
+
+/// A curve that can be differentiated.
+pub trait Derive<T: Float> {
+    /// The resulting curve.
+    type Target;
+
+    /// Perform the calculation.
+    fn derive(&self) -> Self::Target;
+}
+
+impl<T, U> Derive<T> for (U, U)
+where
+    T: Float,
+    U: Derive<T>,
+{
+    type Target = (<U as Derive<T>>::Target, <U as Derive<T>>::Target);
+
+    #[inline]
+    fn derive(&self) -> Self::Target {
+        (self.0.derive(), self.1.derive())
+    }
+}
+
+impl<T, U> Derive<T> for (U, U, U)
+where
+    T: Float,
+    U: Derive<T>,
+{
+    type Target = (
+        <U as Derive<T>>::Target,
+        <U as Derive<T>>::Target,
+        <U as Derive<T>>::Target,
+    );
+
+    #[inline]
+    fn derive(&self) -> Self::Target {
+        (self.0.derive(), self.1.derive(), self.2.derive())
+    }
+}
+
+impl<T, U, const N: usize> Derive<T> for [U; N]
+where
+    T: Float,
+    U: Derive<T>,
+{
+    type Target = [<U as Derive<T>>::Target; N];
+
+    #[inline]
+    fn derive(&self) -> Self::Target {
+        self.each_ref().map(|curve| curve.derive())
+    }
+}
@@ -0,0 +1,134 @@
+use num_traits::Float;
+
+use crate::evaluate::Evaluate;
+
+/// A curve that can be evaluated using forward differences.
+pub trait Difference<T: Float>: Evaluate<T> {
+    /// The state of the differences.
+    type State;
+
+    /// Compute the differences at the start for a step.
+    fn start(&self, step: T) -> Self::State;
+
+    /// Return the current value and advance by one step.
+    fn advance(state: &mut Self::State) -> Self::Target;
+}
+
+/// Forward differences of a polynomial of degree `N - 1`.
+///
+/// The differences are accumulated with compensated summation, which keeps the error from
+/// growing with the number of steps.
+#[derive(Clone, Copy, Debug)]
+pub struct Differences<T, const N: usize> {
+    sums: [T; N],
+    errors: [T; N],
+}
+
+impl<T: Float, const N: usize> Differences<T, N> {
+    /// Create an instance given the coefficients of a polynomial in the power basis in the
+    /// ascending order of powers and a step.
+    ///
+    /// The differences are computed directly from the coefficients, since differencing the values
+    /// at the first steps would lose most of the precision for small steps.
+    pub fn new(coefficients: [T; N], step: T) -> Self {
+        let mut scale = T::one();
+        let coefficients = coefficients.map(|coefficient| {
+            let value = coefficient * scale;
+            scale = scale * step;
+            value
+        });
+        // The k-th difference of the j-th power with a unit step at zero is an integer.
+        let sums = std::array::from_fn(|k| {
+            (k..N).fold(T::zero(), |sum, j| {
+                sum + coefficients[j] * T::from(difference(j, k)).unwrap()
+            })
+        });
+        Self {
+            sums,
+            errors: [T::zero(); N],
+        }
+    }
+
+    /// Return the current value and advance by one step.
+    pub fn advance(&mut self) -> T {
+        let value = self.sums[0] + self.errors[0];
+        for i in 1..N {
+            // Neumaier's variant of the Kahan summation.
+            let addend = self.sums[i] + self.errors[i];
+            let sum = self.sums[i - 1] + addend;
+            if self.sums[i - 1].abs() >= addend.abs() {
+                self.errors[i - 1] = self.errors[i - 1] + ((self.sums[i - 1] - sum) + addend);
+            } else {
+                self.errors[i - 1] = self.errors[i - 1] + ((addend - sum) + self.sums[i - 1]);
+            }
+            self.sums[i - 1] = sum;
+        }
+        value
+    }
+}
+
+impl<T, U> Difference<T> for (U, U)
+where
+    T: Float,
+    U: Difference<T, Target = T>,
+{
+    type State = (U::State, U::State);
+
+    #[inline]
+    fn start(&self, step: T) -> Self::State {
+        (self.0.start(step), self.1.start(step))
+    }
+
+    #[inline]
+    fn advance(state: &mut Self::State) -> Self::Target {
+        (U::advance(&mut state.0), U::advance(&mut state.1))
+    }
+}
+
+impl<T, U> Difference<T> for (U, U, U)
+where
+    T: Float,
+    U: Difference<T, Target = T>,
+{
+    type State = (U::State, U::State, U::State);
+
+    #[inline]
+    fn start(&self, step: T) -> Self::State {
+        (self.0.start(step), self.1.start(step), self.2.start(step))
+    }
+
+    #[inline]
+    fn advance(state: &mut Self::State) -> Self::Target {
+        (
+            U::advance(&mut state.0),
+            U::advance(&mut state.1),
+            U::advance(&mut state.2),
+        )
+    }
+}
+
+fn difference(power: usize, order: usize) -> i64 {
+    let mut binomial = 1;
+    let mut sum = 0;
+    for i in 0..=order {
+        let sign = if (order - i).is_multiple_of(2) { 1 } else { -1 };
+        sum += sign * binomial * (i as i64).pow(power as u32);
+        binomial = binomial * (order - i) as i64 / (i + 1) as i64;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::difference::Differences;
+
+    #[test]
+    fn advance() {
+        let mut differences = Differences::new([1.0, 0.0, 1.0, 0.0], 1.0);
+        let values = (0..6).map(|_| differences.advance()).collect::<Vec<_>>();
+        assert_eq!(values, vec![1.0, 2.0, 5.0, 10.0, 17.0, 26.0]);
+        let mut differences = Differences::new([0.0, 0.0, 0.0, 1.0], 0.5);
+        let values = (0..5).map(|_| differences.advance()).collect::<Vec<_>>();
+        assert_eq!(values, vec![0.0, 0.125, 1.0, 3.375, 8.0]);
+    }
+}
@@ -9,6 +9,19 @@ pub trait Evaluate<T: Float> {
     fn evaluate(&self, t: T) -> Self::Target;
 }
 
+impl<T, U> Evaluate<T> for &U
+where
+    T: Float,
+    U: Evaluate<T> + ?Sized,
+{
+    type Target = <U as Evaluate<T>>::Target;
+
+    #[inline]
+    fn evaluate(&self, t: T) -> Self::Target {
+        (**self).evaluate(t)
+    }
+}
+
 impl<T, U> Evaluate<T> for (U, U)
 where
     T: Float,
@@ -21,3 +34,29 @@ where
         (self.0.evaluate(t), self.1.evaluate(t))
     }
 }
+
+impl<T, U> Evaluate<T> for (U, U, U)
+where
+    T: Float,
+    U: Evaluate<T, Target = T>,
+{
+    type Target = (T, T, T);
+
+    #[inline]
+    fn evaluate(&self, t: T) -> Self::Target {
+        (self.0.evaluate(t), self.1.evaluate(t), self.2.evaluate(t))
+    }
+}
+
+impl<T, U, const N: usize> Evaluate<T> for [U; N]
+where
+    T: Float,
+    U: Evaluate<T, Target = T>,
+{
+    type Target = [T; N];
+
+    #[inline]
+    fn evaluate(&self, t: T) -> Self::Target {
+        self.each_ref().map(|curve| curve.evaluate(t))
+    }
+}
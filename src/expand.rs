@@ -21,3 +21,33 @@ where
         (self.0.expand(), self.1.expand())
     }
 }
+
+impl<T, U> Expand<T> for (U, U, U)
+where
+    T: Float,
+    U: Expand<T>,
+{
+    type Target = (
+        <U as Expand<T>>::Target,
+        <U as Expand<T>>::Target,
+        <U as Expand<T>>::Target,
+    );
+
+    #[inline]
+    fn expand(&self) -> Self::Target {
+        (self.0.expand(), self.1.expand(), self.2.expand())
+    }
+}
+
+impl<T, U, const N: usize> Expand<T> for [U; N]
+where
+    T: Float,
+    U: Expand<T>,
+{
+    type Target = [<U as Expand<T>>::Target; N];
+
+    #[inline]
+    fn expand(&self) -> Self::Target {
+        self.each_ref().map(|curve| curve.expand())
+    }
+}
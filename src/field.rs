@@ -0,0 +1,137 @@
+//! Distance fields.
+
+use num_traits::Float;
+
+use crate::path::{Contour, Path};
+use crate::project::Project;
+use crate::winding::{Fill, Winding};
+
+/// A signed distance field.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Field<T> {
+    width: usize,
+    height: usize,
+    range: T,
+}
+
+impl<T: Float> Field<T> {
+    /// Create an instance.
+    ///
+    /// The distances are clamped to the range in both directions.
+    #[inline]
+    pub fn new(width: usize, height: usize, range: T) -> Self {
+        Self {
+            width,
+            height,
+            range,
+        }
+    }
+
+    /// Compute the signed distances to the outline of a path row by row.
+    ///
+    /// The distances are measured from the centers of the pixels, which are at half-integer
+    /// coordinates, to the closest points on the curves. They are positive inside according to
+    /// the nonzero rule and negative outside. Open contours are closed with lines, as they are
+    /// when determining the inside.
+    pub fn compute(&self, path: &Path<T>) -> Vec<T> {
+        let segments = path
+            .segments()
+            .copied()
+            .chain(path.iter().filter_map(Contour::closing))
+            .collect::<Vec<_>>();
+        let bounds = segments
+            .iter()
+            .map(|segment| segment.bounds())
+            .collect::<Vec<_>>();
+        let half = T::from(0.5).unwrap();
+        let mut values = Vec::with_capacity(self.width * self.height);
+        for j in 0..self.height {
+            for i in 0..self.width {
+                let point = (T::from(i).unwrap() + half, T::from(j).unwrap() + half);
+                let mut distance = self.range;
+                for (segment, bounds) in segments.iter().zip(&bounds) {
+                    if gap(*bounds, point) < distance {
+                        distance = distance.min(segment.project(point).distance);
+                    }
+                }
+                if path.encloses(point, Fill::NonZero) {
+                    values.push(distance);
+                } else {
+                    values.push(-distance);
+                }
+            }
+        }
+        values
+    }
+
+    /// Return the width.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Return the height.
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+}
+
+// The curves lie within the bounding boxes of their control points, so the ones farther away than
+// the closest distance so far are skipped.
+fn gap<T: Float>(((x0, y0), (x1, y1)): ((T, T), (T, T)), (x, y): (T, T)) -> T {
+    let dx = (x0 - x).max(x - x1).max(T::zero());
+    let dy = (y0 - y).max(y - y1).max(T::zero());
+    dx.hypot(dy)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::field::Field;
+    use crate::path::Path;
+    use crate::transform::{Affine, Transform};
+
+    #[test]
+    fn circle() {
+        let k = 0.5522847498307936;
+        let mut path = Path::new();
+        path.move_to((1.0, 0.0));
+        path.cubic_to((1.0, k), (k, 1.0), (0.0, 1.0));
+        path.cubic_to((-k, 1.0), (-1.0, k), (-1.0, 0.0));
+        path.cubic_to((-1.0, -k), (-k, -1.0), (0.0, -1.0));
+        path.cubic_to((k, -1.0), (1.0, -k), (1.0, 0.0));
+        path.close();
+        let transformation = Affine::scale(6.0, 6.0).then(&Affine::translate(8.0, 8.0));
+        let path = path.transform(&transformation);
+        let values = Field::new(16, 16, 4.0).compute(&path);
+        assert_eq!(values.len(), 256);
+        for j in 0..16 {
+            for i in 0..16 {
+                let (x, y) = (i as f64 + 0.5 - 8.0, j as f64 + 0.5 - 8.0);
+                let expected = (6.0 - x.hypot(y)).clamp(-4.0, 4.0);
+                assert::close(values[j * 16 + i], expected, 0.02);
+            }
+        }
+    }
+
+    #[test]
+    fn square() {
+        let mut path = Path::new();
+        path.move_to((1.0, 1.0));
+        path.line_to((3.0, 1.0));
+        path.line_to((3.0, 3.0));
+        path.line_to((1.0, 3.0));
+        path.close();
+        let values = Field::new(4, 4, 10.0).compute(&path);
+        assert_eq!(values[5], 0.5);
+        assert_eq!(values[0], -(0.5f64.hypot(0.5)));
+        assert_eq!(values[4], -0.5);
+
+        let mut open = Path::new();
+        open.move_to((1.0, 1.0));
+        open.line_to((3.0, 1.0));
+        open.line_to((3.0, 3.0));
+        open.line_to((1.0, 3.0));
+        assert_eq!(Field::new(4, 4, 10.0).compute(&open), values);
+    }
+}
@@ -0,0 +1,41 @@
+use num_traits::Float;
+
+/// A curve with a moving frame.
+///
+/// For planar curves, the normal is the tangent rotated counterclockwise, and the curvature is
+/// positive when the curve turns in the direction of the normal. For spatial curves, the normal
+/// is the principal one, and the curvature is nonnegative.
+pub trait Frame<T: Float> {
+    /// The vector type.
+    type Vector;
+
+    /// Compute the unit tangent.
+    fn tangent(&self, t: T) -> Self::Vector;
+
+    /// Compute the unit normal.
+    fn normal(&self, t: T) -> Self::Vector;
+
+    /// Compute the curvature.
+    fn curvature(&self, t: T) -> T;
+
+    /// Compute the radius of curvature, which is infinite for straight parts.
+    #[inline]
+    fn radius(&self, t: T) -> T {
+        self.curvature(t).abs().recip()
+    }
+}
+
+/// A spatial curve with a Frenet–Serret frame.
+pub trait Frenet<T: Float>: Frame<T, Vector = (T, T, T)> {
+    /// Compute the unit binormal.
+    fn binormal(&self, t: T) -> (T, T, T);
+
+    /// Compute the torsion.
+    fn torsion(&self, t: T) -> T;
+
+    /// Compute the tangent, the normal, and the binormal.
+    #[inline]
+    fn frame(&self, t: T) -> [(T, T, T); 3] {
+        [self.tangent(t), self.normal(t), self.binormal(t)]
+    }
+}
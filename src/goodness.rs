@@ -3,3 +3,13 @@ pub trait Goodness<T> {
     /// Check if the candidate is admissible.
     fn admit(&mut self, candidate: &T, original: &T) -> Option<bool>;
 }
+
+impl<T, U> Goodness<T> for &mut U
+where
+    U: Goodness<T>,
+{
+    #[inline]
+    fn admit(&mut self, candidate: &T, original: &T) -> Option<bool> {
+        (**self).admit(candidate, original)
+    }
+}
@@ -0,0 +1,193 @@
+use num_traits::Float;
+
+use crate::derive::Derive;
+use crate::evaluate::Evaluate;
+
+const ITERATIONS: usize = 100;
+const PIECES: usize = 16;
+
+// Gauss–Legendre quadrature on [-1, 1].
+const NODES: [f64; 5] = [
+    -0.906_179_845_938_664,
+    -0.538_469_310_105_683,
+    0.0,
+    0.538_469_310_105_683,
+    0.906_179_845_938_664,
+];
+const WEIGHTS: [f64; 5] = [
+    0.236_926_885_056_189,
+    0.478_628_670_499_366,
+    0.568_888_888_888_889,
+    0.478_628_670_499_366,
+    0.236_926_885_056_189,
+];
+
+/// A curve whose arc length can be measured.
+pub trait Length<T: Float> {
+    /// Compute the speed, which is the norm of the derivative.
+    fn speed(&self, t: T) -> T;
+
+    /// Compute the arc length from the start to a parameter.
+    fn length(&self, t: T) -> T {
+        debug_assert!(T::zero() <= t && t <= T::one());
+        integrate(|t| self.speed(t), t)
+    }
+
+    /// Compute the centroid of the curve as a one-dimensional object weighted by arc length.
+    ///
+    /// The starting point is returned if the length is zero.
+    fn centroid(&self) -> (T, T)
+    where
+        Self: Evaluate<T, Target = (T, T)>,
+    {
+        let length = self.length(T::one());
+        if length.is_zero() {
+            return self.evaluate(T::zero());
+        }
+        let x = integrate(|t| self.evaluate(t).0 * self.speed(t), T::one());
+        let y = integrate(|t| self.evaluate(t).1 * self.speed(t), T::one());
+        (x / length, y / length)
+    }
+
+    /// Find the parameter at an arc length from the start.
+    ///
+    /// The length is clamped to the range of the curve.
+    fn parameter(&self, length: T) -> T {
+        if length <= T::zero() {
+            return T::zero();
+        }
+        let total = self.length(T::one());
+        if length >= total {
+            return T::one();
+        }
+        let (mut lower, mut upper) = (T::zero(), T::one());
+        let mut t = length / total;
+        for _ in 0..ITERATIONS {
+            let error = self.length(t) - length;
+            if error.abs() <= T::epsilon() * total {
+                break;
+            }
+            if error < T::zero() {
+                lower = t;
+            } else {
+                upper = t;
+            }
+            let speed = self.speed(t);
+            t = t - error / speed;
+            if !(lower < t && t < upper) {
+                t = (lower + upper) / (T::one() + T::one());
+            }
+            if upper - lower <= T::epsilon() {
+                break;
+            }
+        }
+        t
+    }
+}
+
+// The interval is split into equal pieces, each integrated using Gauss–Legendre quadrature.
+fn integrate<T, F>(function: F, t: T) -> T
+where
+    T: Float,
+    F: Fn(T) -> T,
+{
+    let half = t / T::from(2 * PIECES).unwrap();
+    (0..PIECES)
+        .map(|i| {
+            let middle = t * T::from(2 * i + 1).unwrap() / T::from(2 * PIECES).unwrap();
+            NODES
+                .iter()
+                .zip(WEIGHTS.iter())
+                .map(|(node, weight)| {
+                    T::from(*weight).unwrap() * function(middle + half * T::from(*node).unwrap())
+                })
+                .fold(T::zero(), |sum, value| sum + value)
+                * half
+        })
+        .fold(T::zero(), |sum, value| sum + value)
+}
+
+impl<T, U> Length<T> for (U, U)
+where
+    T: Float,
+    U: Derive<T>,
+    U::Target: Evaluate<T, Target = T>,
+{
+    fn speed(&self, t: T) -> T {
+        let x = self.0.derive().evaluate(t);
+        let y = self.1.derive().evaluate(t);
+        x.hypot(y)
+    }
+}
+
+impl<T, U> Length<T> for (U, U, U)
+where
+    T: Float,
+    U: Derive<T>,
+    U::Target: Evaluate<T, Target = T>,
+{
+    fn speed(&self, t: T) -> T {
+        let x = self.0.derive().evaluate(t);
+        let y = self.1.derive().evaluate(t);
+        let z = self.2.derive().evaluate(t);
+        x.hypot(y).hypot(z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::arc::Arc;
+    use crate::bezier::{Cubic, Linear, Quadratic};
+    use crate::length::Length;
+    use crate::Evaluate;
+
+    #[test]
+    fn linear() {
+        let curve = (Linear::new(1.0, 4.0), Linear::new(2.0, 6.0));
+        assert::close(curve.length(1.0), 5.0, 1e-12);
+        assert::close(curve.length(0.2), 1.0, 1e-12);
+        assert::close(curve.parameter(2.5), 0.5, 1e-12);
+        assert_eq!(curve.parameter(-1.0), 0.0);
+        assert_eq!(curve.parameter(6.0), 1.0);
+        let (x, y) = curve.centroid();
+        assert::close(&[x, y][..], &[2.5, 4.0][..], 1e-12);
+    }
+
+    #[test]
+    fn quadratic() {
+        let curve = (Quadratic::new(0.0, 0.0, 1.0), Quadratic::new(0.0, 1.0, 1.0));
+        let expected = 1.0 + (1.0 + 2.0f64.sqrt()).ln() / 2.0f64.sqrt();
+        assert::close(curve.length(1.0), expected, 1e-10);
+        assert::close(curve.parameter(expected / 2.0), 0.5, 1e-10);
+    }
+
+    #[test]
+    fn cubic() {
+        let curve = <(Cubic<f64>, Cubic<f64>)>::from(Arc::new(
+            (0.0, 0.0),
+            (2.0, 2.0),
+            0.0,
+            0.0,
+            std::f64::consts::FRAC_PI_2,
+        ));
+        assert::close(curve.length(1.0), std::f64::consts::PI, 1e-3);
+        let t = curve.parameter(1.0);
+        assert::close(curve.length(t), 1.0, 1e-12);
+        let (x, y) = curve.evaluate(t);
+        assert::close(y.atan2(x), 0.5, 1e-3);
+        // The centroid of a quarter of a circle is on the bisector at 2r sqrt(2) / pi.
+        let (x, y) = curve.centroid();
+        assert::close(x, 4.0 / std::f64::consts::PI, 1e-3);
+        assert::close(y, x, 1e-12);
+    }
+
+    #[test]
+    fn space() {
+        let curve = (
+            Linear::new(0.0, 2.0),
+            Linear::new(0.0, 3.0),
+            Linear::new(0.0, 6.0),
+        );
+        assert::close(curve.length(1.0), 7.0, 1e-12);
+    }
+}
@@ -26,22 +26,59 @@
 //! assert_eq!(quadratics.len(), 4);
 //! ```
 
+pub mod arc;
 pub mod bezier;
+pub mod boolean;
+pub mod continuity;
+pub mod dash;
+pub mod field;
+pub mod offset;
+pub mod path;
+#[cfg(feature = "raster")]
+pub mod raster;
+pub mod stroke;
 
 mod align;
 mod approximation;
+mod area;
+mod conversion;
+mod derive;
+mod difference;
 mod evaluate;
 mod expand;
+mod frame;
 mod goodness;
+mod length;
+mod moments;
+mod point;
+mod polynomial;
+mod project;
 mod reduce;
+mod reparameterize;
+mod reverse;
 mod subdivide;
 mod trace;
+mod transform;
+mod winding;
 
 pub use align::Align;
 pub use approximation::Approximation;
+pub use area::{Area, Direction};
+pub use conversion::Conversion;
+pub use derive::Derive;
+pub use difference::{Difference, Differences};
 pub use evaluate::Evaluate;
 pub use expand::Expand;
+pub use frame::{Frame, Frenet};
 pub use goodness::Goodness;
+pub use length::Length;
+pub use moments::{Moment, Moments};
+pub use point::Point;
+pub use project::{Project, Projection};
 pub use reduce::Reduce;
+pub use reparameterize::Reparameterize;
+pub use reverse::Reverse;
 pub use subdivide::Subdivide;
-pub use trace::Trace;
+pub use trace::{ForwardTrace, Trace};
+pub use transform::{Affine, Projective, Transform};
+pub use winding::{Fill, Winding};
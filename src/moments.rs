@@ -0,0 +1,77 @@
+use num_traits::Float;
+
+/// Moments of area.
+///
+/// As with [`Area`](crate::Area), the moments of a curve are the contributions of the curve to
+/// the moments of the region enclosed by a closed sequence of curves, and they are all negated for
+/// clockwise contours.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Moments<T> {
+    /// The area.
+    pub area: T,
+    /// The first moments, which are the integrals of `x` and `y` over the region.
+    pub first: (T, T),
+    /// The second moments, which are the integrals of `x^2`, `y^2`, and `xy` over the region.
+    pub second: (T, T, T),
+}
+
+/// A shape with moments of area.
+pub trait Moment<T: Float> {
+    /// Perform the calculation.
+    fn moments(&self) -> Moments<T>;
+}
+
+impl<T: Float> Moments<T> {
+    /// Compute the centroid, which is absent if the area is zero.
+    pub fn centroid(&self) -> Option<(T, T)> {
+        if self.area.is_zero() {
+            return None;
+        }
+        Some((self.first.0 / self.area, self.first.1 / self.area))
+    }
+
+    /// Compute the second moments with respect to the centroid, which are absent if the area is
+    /// zero.
+    pub fn central(&self) -> Option<(T, T, T)> {
+        let (x, y) = self.centroid()?;
+        Some((
+            self.second.0 - self.area * x * x,
+            self.second.1 - self.area * y * y,
+            self.second.2 - self.area * x * y,
+        ))
+    }
+}
+
+impl<T: Float> std::ops::Add for Moments<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            area: self.area + other.area,
+            first: (self.first.0 + other.first.0, self.first.1 + other.first.1),
+            second: (
+                self.second.0 + other.second.0,
+                self.second.1 + other.second.1,
+                self.second.2 + other.second.2,
+            ),
+        }
+    }
+}
+
+impl<T, U> Moment<T> for [U]
+where
+    T: Float,
+    U: Moment<T>,
+{
+    fn moments(&self) -> Moments<T> {
+        let zero = T::zero();
+        let moments = Moments {
+            area: zero,
+            first: (zero, zero),
+            second: (zero, zero, zero),
+        };
+        self.iter()
+            .fold(moments, |moments, curve| moments + curve.moments())
+    }
+}
@@ -0,0 +1,225 @@
+use num_traits::Float;
+
+use crate::bezier::{Cubic, Quadratic};
+use crate::conversion::Conversion;
+use crate::evaluate::Evaluate;
+use crate::frame::Frame;
+use crate::offset::Offset;
+use crate::polynomial;
+use crate::subdivide::Subdivide;
+
+macro_rules! implement {
+    ($($type:ident),*) => ($(
+        impl<T: Float + Default> Offset<T, ($type<T>, $type<T>)> {
+            /// Approximate with a sequence of curves of the same kind.
+            ///
+            /// The curve is first split at its inflection points and at the points where the
+            /// magnitude of its curvature has a local maximum.
+            pub fn approximate<V>(self, goodness: V) -> Conversion<T, Self, ($type<T>, $type<T>), V> {
+                let mut parts = vec![];
+                let (mut rest, mut last) = (self, T::zero());
+                for t in self.critical() {
+                    let (head, tail) = rest.subdivide((t - last) / (T::one() - last));
+                    parts.push(head);
+                    (rest, last) = (tail, t);
+                }
+                parts.push(rest);
+                Conversion::with_parts(parts, self, goodness)
+            }
+
+            fn critical(&self) -> Vec<T> {
+                let (x, y) = (self.curve.0.polynomial(), self.curve.1.polynomial());
+                let (dx, dy) = (polynomial::derive(&x), polynomial::derive(&y));
+                let (ddx, ddy) = (polynomial::derive(&dx), polynomial::derive(&dy));
+                let (dddx, dddy) = (polynomial::derive(&ddx), polynomial::derive(&ddy));
+                let cross = |one: &[T], other: &[T]| {
+                    polynomial::subtract(
+                        &polynomial::multiply(&dx, other),
+                        &polynomial::multiply(&dy, one),
+                    )
+                };
+                let dot = |one: &[T], other: &[T]| {
+                    polynomial::add(
+                        &polynomial::multiply(&dx, one),
+                        &polynomial::multiply(&dy, other),
+                    )
+                };
+                // The numerator of the derivative of the curvature.
+                let three = T::from(3).unwrap();
+                let slope = polynomial::subtract(
+                    &polynomial::multiply(&cross(&dddx, &dddy), &dot(&dx, &dy)),
+                    &polynomial::multiply(&cross(&ddx, &ddy), &dot(&ddx, &ddy))
+                        .into_iter()
+                        .map(|value| three * value)
+                        .collect::<Vec<_>>(),
+                );
+                let margin = T::epsilon().sqrt();
+                let interval = (margin, T::one() - margin);
+                let mut values = polynomial::roots(&cross(&ddx, &ddy), interval);
+                let curvature = cross(&ddx, &ddy);
+                let change = polynomial::derive(&slope);
+                values.extend(polynomial::roots(&slope, interval).into_iter().filter(|t| {
+                    let curvature = polynomial::evaluate(&curvature, *t);
+                    curvature * polynomial::evaluate(&change, *t) < T::zero()
+                }));
+                values.sort_by(|one, other| one.partial_cmp(other).unwrap());
+                values.dedup_by(|one, other| *one - *other < margin);
+                values
+            }
+        }
+
+        impl<T: Float + Default> Evaluate<T> for Offset<T, ($type<T>, $type<T>)> {
+            type Target = (T, T);
+
+            fn evaluate(&self, t: T) -> Self::Target {
+                let (x, y) = self.curve.evaluate(t);
+                let (u, v) = self.curve.tangent(t);
+                (x - self.distance * v, y + self.distance * u)
+            }
+        }
+
+        impl<T: Float + Default> Subdivide<T> for Offset<T, ($type<T>, $type<T>)> {
+            fn subdivide(&self, t: T) -> (Self, Self) {
+                let (head, tail) = self.curve.subdivide(t);
+                (Offset::new(head, self.distance), Offset::new(tail, self.distance))
+            }
+        }
+    )*);
+}
+
+implement!(Quadratic, Cubic);
+
+impl<T: Float + Default> From<Offset<T, (Quadratic<T>, Quadratic<T>)>>
+    for (Quadratic<T>, Quadratic<T>)
+{
+    fn from(offset: Offset<T, (Quadratic<T>, Quadratic<T>)>) -> Self {
+        let (one, zero) = (T::one(), T::zero());
+        let start = offset.evaluate(zero);
+        let end = offset.evaluate(one);
+        let first = offset.curve.tangent(zero);
+        let second = offset.curve.tangent(one);
+        let determinant = first.0 * second.1 - first.1 * second.0;
+        let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+        let control = if determinant.abs() > T::epsilon() {
+            let scale = (dx * second.1 - dy * second.0) / determinant;
+            (start.0 + scale * first.0, start.1 + scale * first.1)
+        } else {
+            let two = one + one;
+            ((start.0 + end.0) / two, (start.1 + end.1) / two)
+        };
+        (
+            Quadratic::new(start.0, control.0, end.0),
+            Quadratic::new(start.1, control.1, end.1),
+        )
+    }
+}
+
+impl<T: Float + Default> From<Offset<T, (Cubic<T>, Cubic<T>)>> for (Cubic<T>, Cubic<T>) {
+    fn from(offset: Offset<T, (Cubic<T>, Cubic<T>)>) -> Self {
+        let (one, zero) = (T::one(), T::zero());
+        let (x, y) = offset.curve;
+        let start = offset.evaluate(zero);
+        let end = offset.evaluate(one);
+        let scale = |dx: T, dy: T, t: T| {
+            if dx.is_zero() && dy.is_zero() {
+                (dx, dy)
+            } else {
+                let scale = one - offset.distance * offset.curve.curvature(t);
+                (dx * scale, dy * scale)
+            }
+        };
+        let first = scale(x[1] - x[0], y[1] - y[0], zero);
+        let second = scale(x[3] - x[2], y[3] - y[2], one);
+        (
+            Cubic::new(start.0, start.0 + first.0, end.0 - second.0, end.0),
+            Cubic::new(start.1, start.1 + first.1, end.1 - second.1, end.1),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bezier::{Cubic, Quadratic};
+    use crate::evaluate::Evaluate;
+    use crate::offset::goodness::SampledAbsolute;
+    use crate::offset::Offset;
+
+    const K: f64 = 0.5522847498307936;
+
+    #[test]
+    fn circle() {
+        let x = Cubic::new(100.0, 100.0, 100.0 * K, 0.0);
+        let y = Cubic::new(0.0, 100.0 * K, 100.0, 100.0);
+        for distance in [10.0, -10.0] {
+            let goodness = SampledAbsolute::new(0.01, f64::MAX, usize::MAX);
+            let offset = Offset::new((x, y), distance);
+            assert_eq!(offset.critical().len(), 2);
+            let curves = offset.approximate(goodness).collect::<Vec<_>>();
+            assert_eq!(curves.len(), 3);
+            assert::close(curves[0].0[0], 100.0 - distance, 1e-12);
+            assert::close(curves[2].1[3], 100.0 - distance, 1e-12);
+            for curve in curves {
+                for i in 0..=10 {
+                    let (x, y) = curve.evaluate(i as f64 / 10.0);
+                    assert::close(x.hypot(y), 100.0 - distance, 0.05);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn line() {
+        let x = Cubic::new(0.0, 10.0, 20.0, 30.0);
+        let y = Cubic::new(0.0, 0.0, 0.0, 0.0);
+        let goodness = SampledAbsolute::new(1e-9, f64::MAX, usize::MAX);
+        let curves = Offset::new((x, y), 5.0)
+            .approximate(goodness)
+            .collect::<Vec<_>>();
+        assert_eq!(curves, vec![(x, Cubic::new(5.0, 5.0, 5.0, 5.0))]);
+    }
+
+    #[test]
+    fn inflection() {
+        let x = Cubic::new(0.0, 100.0, 0.0, 100.0);
+        let y = Cubic::new(0.0, 0.0, 100.0, 100.0);
+        let offset = Offset::new((x, y), 5.0);
+        assert_eq!(offset.critical().len(), 3);
+        assert::close(offset.critical()[1], 0.5, 1e-12);
+        let goodness = SampledAbsolute::new(0.1, f64::MAX, usize::MAX);
+        let curves = offset.approximate(goodness).collect::<Vec<_>>();
+        assert!(curves.len() >= 2);
+        assert_close(&curves, offset, 0.1);
+    }
+
+    #[test]
+    fn quadratic() {
+        let x = Quadratic::new(0.0, 50.0, 100.0);
+        let y = Quadratic::new(0.0, 100.0, 0.0);
+        let offset = Offset::new((x, y), -10.0);
+        assert_eq!(offset.critical(), vec![0.5]);
+        let goodness = SampledAbsolute::new(0.1, f64::MAX, usize::MAX);
+        let curves = offset.approximate(goodness).collect::<Vec<_>>();
+        assert!(curves.len() >= 2);
+        assert_close(&curves, offset, 0.1);
+    }
+
+    fn assert_close<U, V>(curves: &[U], offset: V, distance: f64)
+    where
+        U: Evaluate<f64, Target = (f64, f64)>,
+        V: Evaluate<f64, Target = (f64, f64)>,
+    {
+        let points = (0..=1000)
+            .map(|i| offset.evaluate(i as f64 / 1000.0))
+            .collect::<Vec<_>>();
+        for curve in curves {
+            for i in 0..=20 {
+                let (x, y) = curve.evaluate(i as f64 / 20.0);
+                let nearest = points
+                    .iter()
+                    .map(|point| (point.0 - x).hypot(point.1 - y))
+                    .fold(f64::MAX, f64::min);
+                assert!(nearest <= distance + 0.05, "{nearest}");
+            }
+        }
+    }
+}
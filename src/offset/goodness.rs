@@ -0,0 +1,96 @@
+//! Goodness of fit.
+
+use num_traits::Float;
+
+use crate::bezier::{Cubic, Quadratic};
+use crate::derive::Derive;
+use crate::evaluate::Evaluate;
+use crate::goodness::Goodness;
+use crate::offset::Offset;
+
+const ITERATIONS: usize = 4;
+const SAMPLES: usize = 16;
+
+/// A goodness of fit based on the absolute distance between points sampled on a candidate curve
+/// and their counterparts on an offset curve.
+///
+/// The counterparts are found by projecting the sampled points onto the underlying curve.
+pub struct SampledAbsolute<T: Float> {
+    absolute_distance: T,
+    relative_distance: T,
+    subdivision: usize,
+    index: usize,
+}
+
+impl<T: Float> SampledAbsolute<T> {
+    /// Create an instance.
+    ///
+    /// * `absolute_distance` dictates the maximum distance between the points of a candidate
+    ///   curve and the offset curve.
+    ///
+    /// * `relative_distance` dictates the maximum distance between the points of a candidate
+    ///   curve and the offset curve relative to the offset distance.
+    ///
+    /// * `subdivision` dictates the maximum number of subdivisions that can be made during a
+    ///   conversion process.
+    #[inline]
+    pub fn new(absolute_distance: T, relative_distance: T, subdivision: usize) -> Self {
+        Self {
+            absolute_distance,
+            relative_distance,
+            subdivision,
+            index: 0,
+        }
+    }
+}
+
+macro_rules! implement {
+    ($($type:ident),*) => ($(
+        impl<T> Goodness<(Offset<T, ($type<T>, $type<T>)>, ($type<T>, $type<T>))>
+            for SampledAbsolute<T>
+        where
+            T: Float + Default,
+        {
+            fn admit(
+                &mut self,
+                candidate: &(Offset<T, ($type<T>, $type<T>)>, ($type<T>, $type<T>)),
+                original: &(Offset<T, ($type<T>, $type<T>)>, ($type<T>, $type<T>)),
+            ) -> Option<bool> {
+                let (offset, curve) = candidate;
+                let size = original.0.distance.abs();
+                let distance = self.absolute_distance.min(self.relative_distance * size);
+                let first = offset.curve.derive();
+                let second = first.derive();
+                let admitted = (0..=SAMPLES).all(|i| {
+                    let t = T::from(i).unwrap() / T::from(SAMPLES).unwrap();
+                    let (x, y) = curve.evaluate(t);
+                    let mut s = t;
+                    for _ in 0..ITERATIONS {
+                        let (u, v) = offset.curve.evaluate(s);
+                        let (du, dv) = first.evaluate(s);
+                        let (ddu, ddv) = second.evaluate(s);
+                        let (dx, dy) = (x - u, y - v);
+                        let value = dx * du + dy * dv;
+                        let derivative = dx * ddu + dy * ddv - du * du - dv * dv;
+                        if derivative.is_zero() {
+                            break;
+                        }
+                        s = (s - value / derivative).max(T::zero()).min(T::one());
+                    }
+                    let (u, v) = offset.evaluate(s);
+                    (x - u).hypot(y - v) <= distance
+                });
+                if admitted {
+                    return Some(true);
+                }
+                if self.index < self.subdivision {
+                    self.index += 1;
+                    return Some(false);
+                }
+                None
+            }
+        }
+    )*);
+}
+
+implement!(Quadratic, Cubic);
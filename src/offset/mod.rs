@@ -0,0 +1,35 @@
+//! Offset curves.
+
+pub mod goodness;
+
+mod bezier;
+
+/// A curve offset along its normal.
+///
+/// Positive distances correspond to the left-hand side with respect to the direction of the
+/// curve, and negative ones to the right-hand side.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Offset<T, U> {
+    curve: U,
+    distance: T,
+}
+
+impl<T: Copy, U> Offset<T, U> {
+    /// Create an instance.
+    #[inline]
+    pub fn new(curve: U, distance: T) -> Self {
+        Self { curve, distance }
+    }
+
+    /// Return the curve.
+    #[inline]
+    pub fn curve(&self) -> &U {
+        &self.curve
+    }
+
+    /// Return the distance.
+    #[inline]
+    pub fn distance(&self) -> T {
+        self.distance
+    }
+}
@@ -0,0 +1,87 @@
+use num_traits::Float;
+
+use crate::approximation::Approximation;
+use crate::bezier::{Cubic, Quadratic};
+use crate::expand::Expand;
+use crate::goodness::Goodness;
+use crate::path::{Path, Segment};
+use crate::reduce::Reduce;
+
+impl<T> Path<T>
+where
+    T: Float + Default,
+    (Cubic<T>, Cubic<T>): Reduce<T, Target = (Quadratic<T>, Quadratic<T>)>,
+    (Quadratic<T>, Quadratic<T>): Expand<T, Target = (Cubic<T>, Cubic<T>)>,
+{
+    /// Approximate the cubic curves with sequences of quadratic ones.
+    ///
+    /// The goodness of fit is shared by all curves, and no path is returned if it stops the
+    /// approximation process.
+    pub fn approximate<V>(&self, mut goodness: V) -> Option<Self>
+    where
+        V: Goodness<(Cubic<T>, Cubic<T>)>,
+    {
+        let mut path = Path::new();
+        for contour in self {
+            path.move_to(contour.start());
+            for segment in contour {
+                let (x, y) = match *segment {
+                    Segment::Cubic(x, y) => (x, y),
+                    segment => {
+                        path.push(segment);
+                        continue;
+                    }
+                };
+                let mut approximation = Approximation::new((x, y), &mut goodness);
+                for (x, y) in approximation.by_ref() {
+                    path.push(Segment::Quadratic(x, y));
+                }
+                if !approximation.is_complete() {
+                    return None;
+                }
+            }
+            if contour.is_closed() {
+                path.close();
+            }
+        }
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bezier::goodness::CrudeIndependentAbsolute;
+    use crate::path::{Path, Segment};
+
+    #[test]
+    fn approximate() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.cubic_to((0.0, 50.0), (90.0, 0.0), (100.0, 0.0));
+        path.line_to((100.0, -10.0));
+        path.close();
+        let goodness = CrudeIndependentAbsolute::new(1.0, f64::MAX, usize::MAX);
+        let other = path.approximate(goodness).unwrap();
+        assert_eq!(other.len(), 1);
+        assert!(other[0].is_closed());
+        assert_eq!(other[0].len(), 6);
+        assert!(other[0][..4]
+            .iter()
+            .all(|segment| matches!(segment, Segment::Quadratic(..))));
+        assert_eq!(other[0][3].end(), (100.0, 0.0));
+        assert_eq!(&other[0][4..], &path[0][1..]);
+
+        let goodness = CrudeIndependentAbsolute::new(1.0, f64::MAX, 2);
+        assert!(path.approximate(goodness).is_none());
+    }
+
+    #[test]
+    fn stopped() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.cubic_to((0.0, 50.0), (90.0, 50.0), (0.0, 0.0));
+        path.close();
+        let goodness = CrudeIndependentAbsolute::new(1.0, f64::MAX, 0);
+        assert!(path.approximate(goodness).is_none());
+    }
+}
@@ -0,0 +1,70 @@
+use num_traits::Float;
+
+use crate::area::Area;
+use crate::bezier::Linear;
+use crate::path::{Contour, Path, Segment};
+
+impl<T> Area<T> for Segment<T>
+where
+    T: Float,
+{
+    fn area(&self) -> T {
+        match self {
+            Self::Linear(x, y) => (*x, *y).area(),
+            Self::Quadratic(x, y) => (*x, *y).area(),
+            Self::Cubic(x, y) => (*x, *y).area(),
+        }
+    }
+}
+
+// Open contours are treated as closed with a linear curve, as they are when filled.
+impl<T> Area<T> for Contour<T>
+where
+    T: Float,
+{
+    fn area(&self) -> T {
+        let area = self.segments.area();
+        match self.segments.last() {
+            Some(segment) if !self.closed => {
+                let ((x, y), (u, v)) = (segment.end(), self.start);
+                area + (Linear::new(x, u), Linear::new(y, v)).area()
+            }
+            _ => area,
+        }
+    }
+}
+
+impl<T> Area<T> for Path<T>
+where
+    T: Float,
+{
+    fn area(&self) -> T {
+        self.contours.area()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::area::{Area, Direction};
+    use crate::path::Path;
+    use crate::reverse::Reverse;
+
+    #[test]
+    fn area() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.line_to((10.0, 0.0));
+        path.quad_to((10.0, 10.0), (0.0, 10.0));
+        assert::close(path.area(), 50.0 + 100.0 / 3.0, 1e-12);
+        path.close();
+        assert::close(path.area(), 50.0 + 100.0 / 3.0, 1e-12);
+        assert_eq!(path[0].direction(), Some(Direction::Counterclockwise));
+        assert_eq!(path.reverse()[0].direction(), Some(Direction::Clockwise));
+        path.move_to((2.0, 2.0));
+        path.line_to((2.0, 4.0));
+        path.line_to((4.0, 4.0));
+        path.line_to((4.0, 2.0));
+        path.close();
+        assert::close(path.area(), 50.0 + 100.0 / 3.0 - 4.0, 1e-12);
+    }
+}
@@ -0,0 +1,70 @@
+use num_traits::Float;
+
+use crate::evaluate::Evaluate;
+use crate::path::{Contour, Segment};
+
+impl<T> Evaluate<T> for Segment<T>
+where
+    T: Float,
+{
+    type Target = (T, T);
+
+    fn evaluate(&self, t: T) -> Self::Target {
+        match self {
+            Self::Linear(x, y) => (x.evaluate(t), y.evaluate(t)),
+            Self::Quadratic(x, y) => (x.evaluate(t), y.evaluate(t)),
+            Self::Cubic(x, y) => (x.evaluate(t), y.evaluate(t)),
+        }
+    }
+}
+
+// The parameter is split evenly between the segments.
+impl<T> Evaluate<T> for Contour<T>
+where
+    T: Float,
+{
+    type Target = (T, T);
+
+    fn evaluate(&self, t: T) -> Self::Target {
+        debug_assert!(T::zero() <= t && t <= T::one());
+        if self.segments.is_empty() {
+            return self.start;
+        }
+        let count = self.segments.len();
+        let t = t * T::from(count).unwrap();
+        let index = t.floor().to_usize().unwrap_or(0).min(count - 1);
+        let t = (t - T::from(index).unwrap()).min(T::one());
+        self.segments[index].evaluate(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::path::Path;
+    use crate::trace::Trace;
+
+    #[test]
+    fn trace() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.line_to((10.0, 0.0));
+        path.quad_to((10.0, 10.0), (0.0, 10.0));
+        path.close();
+        let points = path
+            .iter()
+            .flat_map(|contour| Trace::new(contour, 7))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            points,
+            vec![
+                (0.0, 0.0),
+                (5.0, 0.0),
+                (10.0, 0.0),
+                (7.5, 7.5),
+                (0.0, 10.0),
+                (0.0, 5.0),
+                (0.0, 0.0),
+            ],
+        );
+    }
+}
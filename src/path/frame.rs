@@ -0,0 +1,35 @@
+use num_traits::Float;
+
+use crate::frame::Frame;
+use crate::path::Segment;
+
+impl<T> Frame<T> for Segment<T>
+where
+    T: Float,
+{
+    type Vector = (T, T);
+
+    fn tangent(&self, t: T) -> Self::Vector {
+        match self {
+            Self::Linear(x, y) => (*x, *y).tangent(t),
+            Self::Quadratic(x, y) => (*x, *y).tangent(t),
+            Self::Cubic(x, y) => (*x, *y).tangent(t),
+        }
+    }
+
+    fn normal(&self, t: T) -> Self::Vector {
+        match self {
+            Self::Linear(x, y) => (*x, *y).normal(t),
+            Self::Quadratic(x, y) => (*x, *y).normal(t),
+            Self::Cubic(x, y) => (*x, *y).normal(t),
+        }
+    }
+
+    fn curvature(&self, t: T) -> T {
+        match self {
+            Self::Linear(x, y) => (*x, *y).curvature(t),
+            Self::Quadratic(x, y) => (*x, *y).curvature(t),
+            Self::Cubic(x, y) => (*x, *y).curvature(t),
+        }
+    }
+}
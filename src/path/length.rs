@@ -0,0 +1,17 @@
+use num_traits::Float;
+
+use crate::length::Length;
+use crate::path::Segment;
+
+impl<T> Length<T> for Segment<T>
+where
+    T: Float,
+{
+    fn speed(&self, t: T) -> T {
+        match self {
+            Self::Linear(x, y) => (*x, *y).speed(t),
+            Self::Quadratic(x, y) => (*x, *y).speed(t),
+            Self::Cubic(x, y) => (*x, *y).speed(t),
+        }
+    }
+}
@@ -0,0 +1,328 @@
+//! Paths.
+
+#[cfg(feature = "svg")]
+pub mod svg;
+
+mod approximation;
+mod area;
+mod evaluate;
+mod frame;
+mod length;
+mod moments;
+mod overlap;
+mod project;
+mod reverse;
+mod subdivide;
+mod transform;
+mod winding;
+
+use num_traits::Float;
+
+use crate::bezier::{Cubic, Linear, Quadratic};
+
+/// A path consisting of contours.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path<T> {
+    contours: Vec<Contour<T>>,
+    position: (T, T),
+}
+
+/// A contour consisting of segments.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Contour<T> {
+    start: (T, T),
+    segments: Vec<Segment<T>>,
+    closed: bool,
+}
+
+/// A segment of a contour.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Segment<T> {
+    /// A linear curve.
+    Linear(Linear<T>, Linear<T>),
+    /// A quadratic curve.
+    Quadratic(Quadratic<T>, Quadratic<T>),
+    /// A cubic curve.
+    Cubic(Cubic<T>, Cubic<T>),
+}
+
+impl<T: Float> Path<T> {
+    /// Create an instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            contours: vec![],
+            position: (T::zero(), T::zero()),
+        }
+    }
+
+    /// Start a new contour at a point.
+    pub fn move_to(&mut self, point: (T, T)) {
+        if let Some(contour) = self.contours.last_mut() {
+            if !contour.closed && contour.segments.is_empty() {
+                contour.start = point;
+                self.position = point;
+                return;
+            }
+        }
+        self.contours.push(Contour::new(point));
+        self.position = point;
+    }
+
+    /// Add a linear curve ending at a point.
+    pub fn line_to(&mut self, point: (T, T)) {
+        let (x, y) = self.position;
+        self.push(Segment::Linear(
+            Linear::new(x, point.0),
+            Linear::new(y, point.1),
+        ));
+    }
+
+    /// Add a quadratic curve with a control point ending at a point.
+    pub fn quad_to(&mut self, control: (T, T), point: (T, T)) {
+        let (x, y) = self.position;
+        self.push(Segment::Quadratic(
+            Quadratic::new(x, control.0, point.0),
+            Quadratic::new(y, control.1, point.1),
+        ));
+    }
+
+    /// Add a cubic curve with two control points ending at a point.
+    pub fn cubic_to(&mut self, first: (T, T), second: (T, T), point: (T, T)) {
+        let (x, y) = self.position;
+        self.push(Segment::Cubic(
+            Cubic::new(x, first.0, second.0, point.0),
+            Cubic::new(y, first.1, second.1, point.1),
+        ));
+    }
+
+    /// Close the current contour with a linear curve if its endpoints differ.
+    pub fn close(&mut self) {
+        let contour = match self.contours.last_mut() {
+            Some(contour) if !contour.closed => contour,
+            _ => return,
+        };
+        if self.position != contour.start {
+            let (x, y) = self.position;
+            let (u, v) = contour.start;
+            contour
+                .segments
+                .push(Segment::Linear(Linear::new(x, u), Linear::new(y, v)));
+        }
+        contour.closed = true;
+        self.position = contour.start;
+    }
+
+    /// Add a segment starting at the current position.
+    pub fn push(&mut self, segment: Segment<T>) {
+        match self.contours.last() {
+            Some(contour) if !contour.closed => {}
+            _ => self.contours.push(Contour::new(self.position)),
+        }
+        self.position = segment.end();
+        self.contours.last_mut().unwrap().segments.push(segment);
+    }
+
+    /// Return the current position.
+    #[inline]
+    pub fn position(&self) -> (T, T) {
+        self.position
+    }
+
+    /// Iterate over the segments of all contours.
+    pub fn segments(&self) -> impl Iterator<Item = &Segment<T>> {
+        self.contours.iter().flat_map(|contour| contour.iter())
+    }
+}
+
+impl<T> Contour<T> {
+    /// Create an instance.
+    #[inline]
+    pub fn new(start: (T, T)) -> Self {
+        Self {
+            start,
+            segments: vec![],
+            closed: false,
+        }
+    }
+
+    /// Return the starting point.
+    #[inline]
+    pub fn start(&self) -> (T, T)
+    where
+        T: Copy,
+    {
+        self.start
+    }
+
+    /// Check if the contour is closed.
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+}
+
+impl<T: Float> Contour<T> {
+    /// Return the line that implicitly closes the contour if it is open and not empty.
+    pub(crate) fn closing(&self) -> Option<Segment<T>> {
+        if self.closed {
+            return None;
+        }
+        let ((x, y), (u, v)) = (self.segments.last()?.end(), self.start);
+        Some(Segment::from((Linear::new(x, u), Linear::new(y, v))))
+    }
+}
+
+impl<T: Float> Segment<T> {
+    /// Return the starting point.
+    #[inline]
+    pub fn start(&self) -> (T, T) {
+        match self {
+            Self::Linear(x, y) => (x[0], y[0]),
+            Self::Quadratic(x, y) => (x[0], y[0]),
+            Self::Cubic(x, y) => (x[0], y[0]),
+        }
+    }
+
+    /// Return the ending point.
+    #[inline]
+    pub fn end(&self) -> (T, T) {
+        match self {
+            Self::Linear(x, y) => (x[1], y[1]),
+            Self::Quadratic(x, y) => (x[2], y[2]),
+            Self::Cubic(x, y) => (x[3], y[3]),
+        }
+    }
+
+    /// Return the bounding box of the control points as the lower and upper corners.
+    pub(crate) fn bounds(&self) -> ((T, T), (T, T)) {
+        let (x, y): (&[T], &[T]) = match self {
+            Self::Linear(x, y) => (&x[..], &y[..]),
+            Self::Quadratic(x, y) => (&x[..], &y[..]),
+            Self::Cubic(x, y) => (&x[..], &y[..]),
+        };
+        let fold = |values: &[T]| {
+            values.iter().fold(
+                (T::infinity(), T::neg_infinity()),
+                |(lower, upper), value| (lower.min(*value), upper.max(*value)),
+            )
+        };
+        let ((x0, x1), (y0, y1)) = (fold(x), fold(y));
+        ((x0, y0), (x1, y1))
+    }
+}
+
+impl<T: Float> From<Segment<T>> for Path<T> {
+    #[inline]
+    fn from(segment: Segment<T>) -> Self {
+        let mut path = Path::new();
+        path.move_to(segment.start());
+        path.push(segment);
+        path
+    }
+}
+
+impl<T> From<(Linear<T>, Linear<T>)> for Segment<T> {
+    #[inline]
+    fn from((x, y): (Linear<T>, Linear<T>)) -> Self {
+        Self::Linear(x, y)
+    }
+}
+
+impl<T> From<(Quadratic<T>, Quadratic<T>)> for Segment<T> {
+    #[inline]
+    fn from((x, y): (Quadratic<T>, Quadratic<T>)) -> Self {
+        Self::Quadratic(x, y)
+    }
+}
+
+impl<T> From<(Cubic<T>, Cubic<T>)> for Segment<T> {
+    #[inline]
+    fn from((x, y): (Cubic<T>, Cubic<T>)) -> Self {
+        Self::Cubic(x, y)
+    }
+}
+
+impl<T> std::ops::Deref for Path<T> {
+    type Target = [Contour<T>];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.contours
+    }
+}
+
+impl<T> std::ops::Deref for Contour<T> {
+    type Target = [Segment<T>];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.segments
+    }
+}
+
+impl<'l, T> IntoIterator for &'l Path<T> {
+    type Item = &'l Contour<T>;
+    type IntoIter = std::slice::Iter<'l, Contour<T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.contours.iter()
+    }
+}
+
+impl<'l, T> IntoIterator for &'l Contour<T> {
+    type Item = &'l Segment<T>;
+    type IntoIter = std::slice::Iter<'l, Segment<T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.segments.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bezier::{Linear, Quadratic};
+    use crate::path::{Path, Segment};
+
+    #[test]
+    fn build() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.line_to((10.0, 0.0));
+        path.quad_to((10.0, 10.0), (0.0, 10.0));
+        path.close();
+        path.line_to((-10.0, 0.0));
+        assert_eq!(path.len(), 2);
+        assert_eq!(path[0].len(), 3);
+        assert!(path[0].is_closed());
+        assert_eq!(
+            path[0][1],
+            Segment::Quadratic(
+                Quadratic::new(10.0, 10.0, 0.0),
+                Quadratic::new(0.0, 10.0, 10.0),
+            ),
+        );
+        assert_eq!(
+            path[0][2],
+            Segment::Linear(Linear::new(0.0, 0.0), Linear::new(10.0, 0.0)),
+        );
+        assert_eq!(path[1].start(), (0.0, 0.0));
+        assert!(!path[1].is_closed());
+        assert_eq!(path.segments().count(), 4);
+    }
+
+    #[test]
+    fn move_to() {
+        let mut path = Path::new();
+        path.move_to((1.0, 1.0));
+        path.move_to((2.0, 2.0));
+        path.line_to((3.0, 3.0));
+        path.close();
+        path.close();
+        assert_eq!(path.len(), 1);
+        assert_eq!(path[0].start(), (2.0, 2.0));
+        assert_eq!(path[0].len(), 2);
+    }
+}
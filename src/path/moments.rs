@@ -0,0 +1,77 @@
+use num_traits::Float;
+
+use crate::bezier::Linear;
+use crate::moments::{Moment, Moments};
+use crate::path::{Contour, Path, Segment};
+
+impl<T> Moment<T> for Segment<T>
+where
+    T: Float,
+{
+    fn moments(&self) -> Moments<T> {
+        match self {
+            Self::Linear(x, y) => (*x, *y).moments(),
+            Self::Quadratic(x, y) => (*x, *y).moments(),
+            Self::Cubic(x, y) => (*x, *y).moments(),
+        }
+    }
+}
+
+// Open contours are treated as closed with a linear curve, as they are when filled.
+impl<T> Moment<T> for Contour<T>
+where
+    T: Float,
+{
+    fn moments(&self) -> Moments<T> {
+        let moments = self.segments.moments();
+        match self.segments.last() {
+            Some(segment) if !self.closed => {
+                let ((x, y), (u, v)) = (segment.end(), self.start);
+                moments + (Linear::new(x, u), Linear::new(y, v)).moments()
+            }
+            _ => moments,
+        }
+    }
+}
+
+impl<T> Moment<T> for Path<T>
+where
+    T: Float,
+{
+    fn moments(&self) -> Moments<T> {
+        self.contours.moments()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::arc::Arc;
+    use crate::bezier::Cubic;
+    use crate::moments::Moment;
+    use crate::path::Path;
+
+    #[test]
+    fn ring() {
+        let mut path = Path::new();
+        for (radius, sweep) in [
+            (4.0, std::f64::consts::FRAC_PI_2),
+            (2.0, -std::f64::consts::FRAC_PI_2),
+        ] {
+            path.move_to((3.0 + radius, 1.0));
+            for i in 0..4 {
+                let arc = Arc::new((3.0, 1.0), (radius, radius), 0.0, i as f64 * sweep, sweep);
+                path.push(<(Cubic<f64>, Cubic<f64>)>::from(arc).into());
+            }
+            path.close();
+        }
+        let moments = path.moments();
+        assert::close(moments.area, 12.0 * std::f64::consts::PI, 0.1);
+        let (x, y) = moments.centroid().unwrap();
+        assert::close(&[x, y][..], &[3.0, 1.0][..], 1e-9);
+        let (xx, yy, xy) = moments.central().unwrap();
+        let expected = std::f64::consts::PI * (256.0 - 16.0) / 4.0;
+        assert::close(xx, expected, 1.0);
+        assert::close(yy, xx, 1e-9);
+        assert::close(xy, 0.0, 1e-9);
+    }
+}
@@ -0,0 +1,86 @@
+use num_traits::Float;
+
+use crate::boolean::{Boolean, Error, Operation};
+use crate::path::Path;
+use crate::winding::Fill;
+
+impl<T> Path<T>
+where
+    T: Float + Default,
+{
+    /// Remove overlaps between and within contours.
+    ///
+    /// The result covers the same points as the path under the nonzero rule and has no contours
+    /// crossing each other. The segments keep their kinds. Outer contours are counterclockwise
+    /// and inner ones are clockwise when the y-axis points up, as in PostScript, and they are to
+    /// be reversed for TrueType. An error is returned if the operation fails, as described in
+    /// [`Boolean::apply`].
+    pub fn remove_overlaps(&self, tolerance: T) -> Result<Self, Error> {
+        Boolean::new(Operation::Union, Fill::NonZero, tolerance).apply(self, &Path::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::area::{Area, Direction};
+    use crate::path::{Path, Segment};
+    use crate::winding::{Fill, Winding};
+
+    #[test]
+    fn bowtie() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.line_to((2.0, 2.0));
+        path.line_to((2.0, 0.0));
+        path.line_to((0.0, 2.0));
+        path.close();
+        let result = path.remove_overlaps(1e-9).unwrap();
+        assert_eq!(result.len(), 2);
+        assert::close(result.area(), 2.0, 1e-12);
+        for contour in &result {
+            assert_eq!(contour.direction(), Some(Direction::Counterclockwise));
+        }
+    }
+
+    #[test]
+    fn self_loop() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.cubic_to((3.0, 3.0), (-1.0, 3.0), (2.0, 0.0));
+        path.close();
+        assert_eq!(path.winding((1.0, 1.5)), 1);
+        let result = path.remove_overlaps(1e-9).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result.winding((1.0, 1.5)), 1);
+        assert_eq!(result.winding((1.0, 0.1)), 1);
+    }
+
+    #[test]
+    fn glyph() {
+        let mut path = Path::new();
+        for (x, y) in [(0.0, 0.0), (300.0, 100.0)] {
+            path.move_to((x, y));
+            path.quad_to((x + 250.0, y - 100.0), (x + 500.0, y));
+            path.quad_to((x + 600.0, y + 250.0), (x + 500.0, y + 500.0));
+            path.line_to((x, y + 500.0));
+            path.close();
+            path.move_to((x + 100.0, y + 100.0));
+            path.line_to((x + 100.0, y + 200.0));
+            path.line_to((x + 200.0, y + 200.0));
+            path.line_to((x + 200.0, y + 100.0));
+            path.close();
+        }
+        let result = path.remove_overlaps(1e-9).unwrap();
+        assert!(result
+            .segments()
+            .all(|segment| matches!(segment, Segment::Linear(..) | Segment::Quadratic(..))));
+        for point in [(50.0, 50.0), (150.0, 150.0), (450.0, 250.0), (700.0, 550.0)] {
+            assert_eq!(
+                result.encloses(point, Fill::NonZero),
+                path.encloses(point, Fill::NonZero),
+            );
+            assert!(result.winding(point).abs() <= 1);
+        }
+        assert_eq!(result.winding((450.0, 250.0)), 1);
+    }
+}
@@ -0,0 +1,17 @@
+use num_traits::Float;
+
+use crate::path::Segment;
+use crate::project::{Project, Projection};
+
+impl<T> Project<T> for Segment<T>
+where
+    T: Float,
+{
+    fn project(&self, point: (T, T)) -> Projection<T> {
+        match self {
+            Self::Linear(x, y) => (*x, *y).project(point),
+            Self::Quadratic(x, y) => (*x, *y).project(point),
+            Self::Cubic(x, y) => (*x, *y).project(point),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use num_traits::Float;
+
+use crate::path::{Contour, Path, Segment};
+use crate::reverse::Reverse;
+
+impl<T> Reverse<T> for Segment<T>
+where
+    T: Float,
+{
+    fn reverse(&self) -> Self {
+        match self {
+            Self::Linear(x, y) => (*x, *y).reverse().into(),
+            Self::Quadratic(x, y) => (*x, *y).reverse().into(),
+            Self::Cubic(x, y) => (*x, *y).reverse().into(),
+        }
+    }
+}
+
+impl<T> Reverse<T> for Contour<T>
+where
+    T: Float,
+{
+    fn reverse(&self) -> Self {
+        Self {
+            start: self.segments.last().map_or(self.start, Segment::end),
+            segments: self.segments.iter().rev().map(Reverse::reverse).collect(),
+            closed: self.closed,
+        }
+    }
+}
+
+impl<T> Reverse<T> for Path<T>
+where
+    T: Float,
+{
+    fn reverse(&self) -> Self {
+        let contours = self
+            .contours
+            .iter()
+            .map(Reverse::reverse)
+            .collect::<Vec<_>>();
+        let position = match contours.last() {
+            Some(contour) if contour.closed => contour.start,
+            Some(contour) => contour.segments.last().map_or(contour.start, Segment::end),
+            None => self.position,
+        };
+        Self { contours, position }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::path::Path;
+    use crate::reverse::Reverse;
+
+    #[test]
+    fn reverse() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.line_to((10.0, 0.0));
+        path.quad_to((10.0, 10.0), (0.0, 10.0));
+        path.close();
+        path.move_to((20.0, 0.0));
+        path.cubic_to((30.0, 0.0), (30.0, 10.0), (20.0, 10.0));
+
+        let mut expected = Path::new();
+        expected.move_to((0.0, 0.0));
+        expected.line_to((0.0, 10.0));
+        expected.quad_to((10.0, 10.0), (10.0, 0.0));
+        expected.line_to((0.0, 0.0));
+        expected.close();
+        expected.move_to((20.0, 10.0));
+        expected.cubic_to((30.0, 10.0), (30.0, 0.0), (20.0, 0.0));
+
+        assert_eq!(path.reverse(), expected);
+        assert_eq!(path.reverse().reverse(), path);
+    }
+}
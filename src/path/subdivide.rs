@@ -0,0 +1,26 @@
+use num_traits::Float;
+
+use crate::path::Segment;
+use crate::subdivide::Subdivide;
+
+impl<T> Subdivide<T> for Segment<T>
+where
+    T: Float + Default,
+{
+    fn subdivide(&self, t: T) -> (Self, Self) {
+        match self {
+            Self::Linear(x, y) => {
+                let (head, tail) = (*x, *y).subdivide(t);
+                (head.into(), tail.into())
+            }
+            Self::Quadratic(x, y) => {
+                let (head, tail) = (*x, *y).subdivide(t);
+                (head.into(), tail.into())
+            }
+            Self::Cubic(x, y) => {
+                let (head, tail) = (*x, *y).subdivide(t);
+                (head.into(), tail.into())
+            }
+        }
+    }
+}
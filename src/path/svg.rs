@@ -0,0 +1,429 @@
+//! SVG path data.
+//!
+//! Paths are rendered into path data via [`std::fmt::Display`], which honors the precision of
+//! the formatter, and they are parsed from path data via [`parse`].
+
+use std::fmt::{Display, Formatter, Result as FormatResult};
+use std::str::FromStr;
+
+use num_traits::Float;
+
+use crate::arc::goodness::SampledRadialAbsolute;
+use crate::arc::Arc;
+use crate::bezier::Cubic;
+use crate::conversion::Conversion;
+use crate::path::{Contour, Path, Segment};
+
+// A full ellipse takes a dozen curves at the given accuracy, so running out of subdivisions means
+// that the arc is degenerate.
+const SUBDIVISION: usize = 256;
+
+/// An error.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Error {
+    position: usize,
+}
+
+impl Error {
+    /// Return the position in the path data where the error occurred.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, formatter: &mut Formatter) -> FormatResult {
+        write!(
+            formatter,
+            "failed to parse path data at position {}",
+            self.position
+        )
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parse path data.
+///
+/// Arcs are converted into cubic curves deviating from them by at most 0.1% of the larger radius.
+/// Numbers that are not finite and arcs that cannot be converted are rejected.
+pub fn parse<T>(data: &str) -> Result<Path<T>, Error>
+where
+    T: Float + FromStr,
+{
+    let mut parser = Parser {
+        data: data.as_bytes(),
+        position: 0,
+    };
+    let mut path = Path::new();
+    let mut command = None;
+    let mut control = None;
+    let mut start = (T::zero(), T::zero());
+    loop {
+        parser.skip();
+        let letter = match parser.peek() {
+            None => break,
+            Some(letter) if letter.is_ascii_alphabetic() => {
+                parser.position += 1;
+                letter
+            }
+            Some(_) => match command {
+                Some(b'M') => b'L',
+                Some(b'm') => b'l',
+                Some(b'Z' | b'z') | None => return Err(parser.error()),
+                Some(letter) => letter,
+            },
+        };
+        if command.is_none() && letter != b'M' && letter != b'm' {
+            parser.position -= 1;
+            return Err(parser.error());
+        }
+        command = Some(letter);
+        let relative = letter.is_ascii_lowercase();
+        let (x, y) = path.position;
+        let offset = |(u, v): (T, T)| if relative { (x + u, y + v) } else { (u, v) };
+        let previous = control.take();
+        match letter.to_ascii_uppercase() {
+            b'M' => {
+                let point = offset(parser.point()?);
+                path.move_to(point);
+                start = point;
+            }
+            b'L' => path.line_to(offset(parser.point()?)),
+            b'H' => {
+                let u = parser.number()?;
+                path.line_to((if relative { x + u } else { u }, y));
+            }
+            b'V' => {
+                let v = parser.number()?;
+                path.line_to((x, if relative { y + v } else { v }));
+            }
+            b'C' => {
+                let first = offset(parser.point()?);
+                let second = offset(parser.point()?);
+                path.cubic_to(first, second, offset(parser.point()?));
+                control = Some((b'C', second));
+            }
+            b'S' => {
+                let first = reflect(previous, b'C', (x, y));
+                let second = offset(parser.point()?);
+                path.cubic_to(first, second, offset(parser.point()?));
+                control = Some((b'C', second));
+            }
+            b'Q' => {
+                let first = offset(parser.point()?);
+                path.quad_to(first, offset(parser.point()?));
+                control = Some((b'Q', first));
+            }
+            b'T' => {
+                let first = reflect(previous, b'Q', (x, y));
+                path.quad_to(first, offset(parser.point()?));
+                control = Some((b'Q', first));
+            }
+            b'A' => {
+                parser.skip();
+                let position = parser.position;
+                let radii = parser.point()?;
+                let rotation = parser.number::<T>()?.to_radians();
+                let large = parser.flag()?;
+                let sweep = parser.flag()?;
+                let point = offset(parser.point()?);
+                arc_to(&mut path, radii, rotation, large, sweep, point)
+                    .ok_or(Error { position })?;
+            }
+            b'Z' => {
+                path.close();
+                path.position = start;
+            }
+            _ => {
+                parser.position -= 1;
+                return Err(parser.error());
+            }
+        }
+    }
+    Ok(path)
+}
+
+impl<T: Display> Display for Path<T> {
+    fn fmt(&self, formatter: &mut Formatter) -> FormatResult {
+        for (i, contour) in self.contours.iter().enumerate() {
+            if i > 0 {
+                formatter.write_str(" ")?;
+            }
+            contour.fmt(formatter)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display> Display for Contour<T> {
+    fn fmt(&self, formatter: &mut Formatter) -> FormatResult {
+        formatter.write_str("M")?;
+        write_numbers(formatter, [&self.start.0, &self.start.1])?;
+        for segment in &self.segments {
+            formatter.write_str(" ")?;
+            segment.fmt(formatter)?;
+        }
+        if self.closed {
+            formatter.write_str(" Z")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display> Display for Segment<T> {
+    fn fmt(&self, formatter: &mut Formatter) -> FormatResult {
+        match self {
+            Self::Linear(x, y) => {
+                formatter.write_str("L")?;
+                write_numbers(formatter, [&x[1], &y[1]])
+            }
+            Self::Quadratic(x, y) => {
+                formatter.write_str("Q")?;
+                write_numbers(formatter, [&x[1], &y[1], &x[2], &y[2]])
+            }
+            Self::Cubic(x, y) => {
+                formatter.write_str("C")?;
+                write_numbers(formatter, [&x[1], &y[1], &x[2], &y[2], &x[3], &y[3]])
+            }
+        }
+    }
+}
+
+struct Parser<'l> {
+    data: &'l [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.position).copied()
+    }
+
+    fn skip(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0C' | b',') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn error(&self) -> Error {
+        Error {
+            position: self.position,
+        }
+    }
+
+    fn flag(&mut self) -> Result<bool, Error> {
+        self.skip();
+        let value = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(self.error()),
+        };
+        self.position += 1;
+        Ok(value)
+    }
+
+    fn number<T: Float + FromStr>(&mut self) -> Result<T, Error> {
+        self.skip();
+        let start = self.position;
+        if let Some(b'+' | b'-') = self.peek() {
+            self.position += 1;
+        }
+        let mut digits = self.digits();
+        if let Some(b'.') = self.peek() {
+            self.position += 1;
+            digits += self.digits();
+        }
+        if digits == 0 {
+            self.position = start;
+            return Err(self.error());
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            let position = self.position;
+            self.position += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.position += 1;
+            }
+            if self.digits() == 0 {
+                self.position = position;
+            }
+        }
+        std::str::from_utf8(&self.data[start..self.position])
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .filter(|value: &T| value.is_finite())
+            .ok_or(Error { position: start })
+    }
+
+    fn point<T: Float + FromStr>(&mut self) -> Result<(T, T), Error> {
+        Ok((self.number()?, self.number()?))
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.position;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+        self.position - start
+    }
+}
+
+fn arc_to<T: Float>(
+    path: &mut Path<T>,
+    radii: (T, T),
+    rotation: T,
+    large: bool,
+    sweep: bool,
+    point: (T, T),
+) -> Option<()> {
+    let from = path.position;
+    let arc = match Arc::from_endpoints(from, point, radii, rotation, large, sweep) {
+        Some(arc) => arc,
+        _ if from == point => return Some(()),
+        _ => {
+            path.line_to(point);
+            return Some(());
+        }
+    };
+    let goodness = SampledRadialAbsolute::new(T::max_value(), T::from(1e-3).unwrap(), SUBDIVISION);
+    let mut conversion = Conversion::<T, _, (Cubic<T>, Cubic<T>), _>::new(arc, goodness);
+    let mut curves = conversion.by_ref().collect::<Vec<_>>();
+    if conversion.remainder().next().is_some() {
+        return None;
+    }
+    let count = curves.len();
+    for (i, (x, y)) in curves.iter_mut().enumerate() {
+        (x[0], y[0]) = path.position;
+        if i + 1 == count {
+            (x[3], y[3]) = point;
+        }
+        path.push(Segment::Cubic(*x, *y));
+    }
+    Some(())
+}
+
+fn reflect<T: Float>(control: Option<(u8, (T, T))>, kind: u8, (x, y): (T, T)) -> (T, T) {
+    match control {
+        Some((other, (u, v))) if other == kind => (x + x - u, y + y - v),
+        _ => (x, y),
+    }
+}
+
+fn write_numbers<T: Display, const N: usize>(
+    formatter: &mut Formatter,
+    numbers: [&T; N],
+) -> FormatResult {
+    for (i, number) in numbers.into_iter().enumerate() {
+        if i > 0 {
+            formatter.write_str(",")?;
+        }
+        number.fmt(formatter)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approximation::Approximation;
+    use crate::bezier::goodness::CrudeIndependentAbsolute;
+    use crate::bezier::{Cubic, Linear, Quadratic};
+    use crate::path::svg::{parse, Error};
+    use crate::path::{Path, Segment};
+
+    #[test]
+    fn parse_absolute() {
+        let path = parse::<f64>("M0,0 L10,0 H20 V10 C20,20 10,20 0,20 S-10,10 0,0 Z").unwrap();
+        assert_eq!(
+            format!("{path}"),
+            "M0,0 L10,0 L20,0 L20,10 C20,20,10,20,0,20 C-10,20,-10,10,0,0 Z",
+        );
+    }
+
+    #[test]
+    fn parse_relative() {
+        let path = parse::<f64>("m1 1l10-0h10v10q0 10-10 10t-10-10 10-10zm5 5 1 1").unwrap();
+        assert_eq!(
+            format!("{path}"),
+            "M1,1 L11,1 L21,1 L21,11 Q21,21,11,21 Q1,21,1,11 Q1,1,11,1 L1,1 Z M6,6 L7,7",
+        );
+    }
+
+    #[test]
+    fn parse_numbers() {
+        let path = parse::<f64>("M.5.5L-1e1,+2E-1 1.5e 2").unwrap_err();
+        assert_eq!(path, Error { position: 20 });
+        let path = parse::<f64>("M.5.5L-1e1,+2E-1").unwrap();
+        assert_eq!(
+            path[0][0],
+            Segment::Linear(Linear::new(0.5, -10.0), Linear::new(0.5, 0.2)),
+        );
+    }
+
+    #[test]
+    fn parse_arc() {
+        let path = parse::<f64>("M0,0 A1,1 0 1,1 0,2 a1 1 0 0 1 0-2").unwrap();
+        assert_eq!(path[0].len(), 4);
+        assert_eq!(path[0][0].start(), (0.0, 0.0));
+        assert_eq!(path[0][1].end(), (0.0, 2.0));
+        assert_eq!(path[0][3].end(), (0.0, 0.0));
+        for segment in path.segments() {
+            let (x, y) = segment.end();
+            assert::close(x * x + (y - 1.0) * (y - 1.0), 1.0, 1e-12);
+        }
+        let path = parse::<f64>("M0,0 A0,1 0 0,0 1,1 A1,1 0 0,0 1,1").unwrap();
+        assert_eq!(format!("{path}"), "M0,0 L1,1");
+    }
+
+    #[test]
+    fn parse_error() {
+        assert_eq!(parse::<f64>("L1,1").unwrap_err().position(), 0);
+        assert_eq!(parse::<f64>("M1,1 X").unwrap_err().position(), 5);
+        assert_eq!(parse::<f64>("M1,1 L1").unwrap_err().position(), 7);
+        assert_eq!(
+            parse::<f64>("M1,1 A1,1 0 2 0 1,1").unwrap_err().position(),
+            12
+        );
+        assert_eq!(parse::<f64>("M1,1 Z 1").unwrap_err().position(), 7);
+        assert_eq!(parse::<f64>("M1,1 L1e400,1").unwrap_err().position(), 6);
+        assert_eq!(
+            parse::<f64>("M0,0 A1e400,1 0 0,1 1,1")
+                .unwrap_err()
+                .position(),
+            6
+        );
+        assert_eq!(
+            parse::<f32>("M0,0 a1,1 0 0,1 1e39,1")
+                .unwrap_err()
+                .position(),
+            16
+        );
+    }
+
+    #[test]
+    fn render() {
+        let goodness = CrudeIndependentAbsolute::new(1.0, f64::MAX, usize::MAX);
+        let x = Cubic::new(0.0, 0.0, 90.0, 100.0);
+        let y = Cubic::new(0.0, 50.0, 0.0, 0.0);
+        let mut path = Path::new();
+        for curve in Approximation::new((x, y), goodness) {
+            path.push(Segment::from(curve));
+        }
+        assert_eq!(
+            format!("{path:.0}"),
+            "M0,0 Q1,18,14,21 Q28,24,46,19 Q65,13,80,7 Q96,1,100,0",
+        );
+        let x = Quadratic::new(0.0, 0.5, 1.0);
+        let y = Quadratic::new(0.0, 0.25, 0.0);
+        assert_eq!(
+            format!("{:.2}", Segment::from((x, y))),
+            "Q0.50,0.25,1.00,0.00"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let data = "M0,0 C0,50,90,0,100,0 Q50,50,0,0 Z M10,10 L20,20";
+        assert_eq!(format!("{}", parse::<f64>(data).unwrap()), data);
+    }
+}
@@ -0,0 +1,78 @@
+use num_traits::Float;
+
+use crate::path::{Contour, Path, Segment};
+use crate::transform::{Affine, Transform};
+
+impl<T: Float> Transform<T, Affine<T>> for Segment<T> {
+    type Target = Self;
+
+    fn transform(&self, transformation: &Affine<T>) -> Self::Target {
+        match self {
+            Self::Linear(x, y) => (*x, *y).transform(transformation).into(),
+            Self::Quadratic(x, y) => (*x, *y).transform(transformation).into(),
+            Self::Cubic(x, y) => (*x, *y).transform(transformation).into(),
+        }
+    }
+}
+
+impl<T: Float> Transform<T, Affine<T>> for Contour<T> {
+    type Target = Self;
+
+    fn transform(&self, transformation: &Affine<T>) -> Self::Target {
+        Self {
+            start: transformation.apply(self.start),
+            segments: self
+                .segments
+                .iter()
+                .map(|segment| segment.transform(transformation))
+                .collect(),
+            closed: self.closed,
+        }
+    }
+}
+
+impl<T: Float> Transform<T, Affine<T>> for Path<T> {
+    type Target = Self;
+
+    fn transform(&self, transformation: &Affine<T>) -> Self::Target {
+        Self {
+            contours: self
+                .contours
+                .iter()
+                .map(|contour| contour.transform(transformation))
+                .collect(),
+            position: transformation.apply(self.position),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::path::Path;
+    use crate::transform::{Affine, Transform};
+
+    #[test]
+    fn transform() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.line_to((1.0, 0.0));
+        path.quad_to((1.0, 1.0), (0.0, 1.0));
+        path.close();
+        path.move_to((2.0, 2.0));
+        path.cubic_to((3.0, 2.0), (3.0, 3.0), (2.0, 3.0));
+        let transformation = Affine::scale(2.0, 3.0).then(&Affine::translate(1.0, -1.0));
+        let result = path.transform(&transformation);
+        assert_eq!(result.len(), 2);
+        assert!(result[0].is_closed());
+        assert!(!result[1].is_closed());
+        assert_eq!(result.position(), (5.0, 8.0));
+        for (segment, other) in path.segments().zip(result.segments()) {
+            assert_eq!(
+                std::mem::discriminant(segment),
+                std::mem::discriminant(other),
+            );
+            assert_eq!(transformation.apply(segment.start()), other.start());
+            assert_eq!(transformation.apply(segment.end()), other.end());
+        }
+    }
+}
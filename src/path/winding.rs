@@ -0,0 +1,75 @@
+use num_traits::Float;
+
+use crate::bezier::Linear;
+use crate::path::{Contour, Path, Segment};
+use crate::winding::Winding;
+
+impl<T> Winding<T> for Segment<T>
+where
+    T: Float,
+{
+    fn winding(&self, point: (T, T)) -> isize {
+        match self {
+            Self::Linear(x, y) => (*x, *y).winding(point),
+            Self::Quadratic(x, y) => (*x, *y).winding(point),
+            Self::Cubic(x, y) => (*x, *y).winding(point),
+        }
+    }
+}
+
+// Open contours are treated as closed with a linear curve, as they are when filled.
+impl<T> Winding<T> for Contour<T>
+where
+    T: Float,
+{
+    fn winding(&self, point: (T, T)) -> isize {
+        let winding = self.segments.winding(point);
+        match self.segments.last() {
+            Some(segment) if !self.closed => {
+                let ((x, y), (u, v)) = (segment.end(), self.start);
+                winding + (Linear::new(x, u), Linear::new(y, v)).winding(point)
+            }
+            _ => winding,
+        }
+    }
+}
+
+impl<T> Winding<T> for Path<T>
+where
+    T: Float,
+{
+    fn winding(&self, point: (T, T)) -> isize {
+        self.contours.winding(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::path::Path;
+    use crate::reverse::Reverse;
+    use crate::winding::{Fill, Winding};
+
+    #[test]
+    fn winding() {
+        let mut path = Path::new();
+        for size in [10.0, 6.0] {
+            path.move_to((0.0, 0.0));
+            path.line_to((size, 0.0));
+            path.quad_to((size, size), (0.0, size));
+            path.close();
+        }
+        assert_eq!(path.winding((1.0, 1.0)), 2);
+        assert_eq!(path.winding((8.0, 1.0)), 1);
+        assert_eq!(path.winding((-1.0, 1.0)), 0);
+        assert!(!path.encloses((1.0, 1.0), Fill::EvenOdd));
+        assert!(path.encloses((8.0, 1.0), Fill::EvenOdd));
+        assert_eq!(path.reverse().winding((1.0, 1.0)), -2);
+
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.line_to((4.0, 0.0));
+        path.line_to((4.0, 4.0));
+        assert_eq!(path.winding((3.0, 1.0)), 1);
+        assert_eq!(path.winding((1.0, 3.0)), 0);
+    }
+}
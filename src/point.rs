@@ -0,0 +1,209 @@
+use num_traits::Float;
+
+/// A point.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Point<T> {
+    /// The horizontal coordinate.
+    pub x: T,
+    /// The vertical coordinate.
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    /// Create an instance.
+    #[inline]
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    #[inline]
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    #[inline]
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T> From<[T; 2]> for Point<T> {
+    #[inline]
+    fn from([x, y]: [T; 2]) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for [T; 2] {
+    #[inline]
+    fn from(point: Point<T>) -> Self {
+        [point.x, point.y]
+    }
+}
+
+impl<T: Float> std::ops::Add for Point<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Float> std::ops::Sub for Point<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Float> std::ops::Mul<T> for Point<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: T) -> Self::Output {
+        Self::new(self.x * other, self.y * other)
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<T, U> From<euclid::Point2D<T, U>> for Point<T> {
+    #[inline]
+    fn from(point: euclid::Point2D<T, U>) -> Self {
+        Self::new(point.x, point.y)
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<T, U> From<Point<T>> for euclid::Point2D<T, U> {
+    #[inline]
+    fn from(point: Point<T>) -> Self {
+        euclid::Point2D::new(point.x, point.y)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Vec2> for Point<f32> {
+    #[inline]
+    fn from(point: glam::Vec2) -> Self {
+        Self::new(point.x, point.y)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Point<f32>> for glam::Vec2 {
+    #[inline]
+    fn from(point: Point<f32>) -> Self {
+        glam::Vec2::new(point.x, point.y)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::DVec2> for Point<f64> {
+    #[inline]
+    fn from(point: glam::DVec2) -> Self {
+        Self::new(point.x, point.y)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Point<f64>> for glam::DVec2 {
+    #[inline]
+    fn from(point: Point<f64>) -> Self {
+        glam::DVec2::new(point.x, point.y)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T> From<mint::Point2<T>> for Point<T> {
+    #[inline]
+    fn from(point: mint::Point2<T>) -> Self {
+        Self::new(point.x, point.y)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T> From<Point<T>> for mint::Point2<T> {
+    #[inline]
+    fn from(point: Point<T>) -> Self {
+        mint::Point2 {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::Scalar> From<nalgebra::Point2<T>> for Point<T> {
+    #[inline]
+    fn from(point: nalgebra::Point2<T>) -> Self {
+        Self::new(point.x.clone(), point.y.clone())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::Scalar> From<Point<T>> for nalgebra::Point2<T> {
+    #[inline]
+    fn from(point: Point<T>) -> Self {
+        nalgebra::Point2::new(point.x, point.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point::Point;
+
+    #[test]
+    fn convert() {
+        let point = Point::new(1.0, 2.0);
+        assert_eq!(<(f64, f64)>::from(point), (1.0, 2.0));
+        assert_eq!(<[f64; 2]>::from(point), [1.0, 2.0]);
+        assert_eq!(Point::from((1.0, 2.0)), point);
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint() {
+        let point = Point::new(1.0, 2.0);
+        let other = mint::Point2::from(point);
+        assert_eq!((other.x, other.y), (1.0, 2.0));
+        assert_eq!(Point::from(other), point);
+    }
+
+    #[cfg(feature = "euclid")]
+    #[test]
+    fn euclid() {
+        let point = Point::new(1.0, 2.0);
+        let other = euclid::default::Point2D::from(point);
+        assert_eq!((other.x, other.y), (1.0, 2.0));
+        assert_eq!(Point::from(other), point);
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam() {
+        let point = Point::new(1.0f32, 2.0);
+        let other = glam::Vec2::from(point);
+        assert_eq!((other.x, other.y), (1.0, 2.0));
+        assert_eq!(Point::from(other), point);
+        let point = Point::new(1.0f64, 2.0);
+        let other = glam::DVec2::from(point);
+        assert_eq!((other.x, other.y), (1.0, 2.0));
+        assert_eq!(Point::from(other), point);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn nalgebra() {
+        let point = Point::new(1.0, 2.0);
+        let other = nalgebra::Point2::from(point);
+        assert_eq!((other.x, other.y), (1.0, 2.0));
+        assert_eq!(Point::from(other), point);
+    }
+}
@@ -0,0 +1,158 @@
+//! Polynomials with coefficients given in the ascending order of powers.
+
+use num_traits::Float;
+
+const ITERATIONS: usize = 200;
+
+/// Compute the derivative.
+pub fn derive<T: Float>(coefficients: &[T]) -> Vec<T> {
+    coefficients
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, coefficient)| *coefficient * T::from(i).unwrap())
+        .collect()
+}
+
+/// Evaluate at a point.
+pub fn evaluate<T: Float>(coefficients: &[T], t: T) -> T {
+    coefficients
+        .iter()
+        .rev()
+        .fold(T::zero(), |sum, coefficient| sum * t + *coefficient)
+}
+
+/// Compute the definite integral over the unit interval.
+pub fn integrate<T: Float>(coefficients: &[T]) -> T {
+    coefficients
+        .iter()
+        .enumerate()
+        .fold(T::zero(), |sum, (i, coefficient)| {
+            sum + *coefficient / T::from(i + 1).unwrap()
+        })
+}
+
+/// Compute the product of two polynomials.
+pub fn multiply<T: Float>(one: &[T], other: &[T]) -> Vec<T> {
+    if one.is_empty() || other.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![T::zero(); one.len() + other.len() - 1];
+    for (i, one) in one.iter().enumerate() {
+        for (j, other) in other.iter().enumerate() {
+            result[i + j] = result[i + j] + *one * *other;
+        }
+    }
+    result
+}
+
+/// Compute the sum of two polynomials.
+pub fn add<T: Float>(one: &[T], other: &[T]) -> Vec<T> {
+    combine(one, other, |one, other| one + other)
+}
+
+/// Compute the difference of two polynomials.
+pub fn subtract<T: Float>(one: &[T], other: &[T]) -> Vec<T> {
+    combine(one, other, |one, other| one - other)
+}
+
+/// Find the distinct roots within an interval in the ascending order.
+///
+/// The roots are isolated using the ones of the derivative and then refined using bisection.
+pub fn roots<T: Float>(coefficients: &[T], (lower, upper): (T, T)) -> Vec<T> {
+    let mut coefficients = coefficients;
+    while let Some((last, rest)) = coefficients.split_last() {
+        if !last.is_zero() {
+            break;
+        }
+        coefficients = rest;
+    }
+    match coefficients.len() {
+        0 | 1 => return vec![],
+        2 => {
+            let root = -coefficients[0] / coefficients[1];
+            return if lower <= root && root <= upper {
+                vec![root]
+            } else {
+                vec![]
+            };
+        }
+        _ => {}
+    }
+    let mut points = vec![lower];
+    points.extend(roots(&derive(coefficients), (lower, upper)));
+    points.push(upper);
+    let mut result: Vec<T> = vec![];
+    let mut push = |root: T| {
+        if result.last().is_none_or(|last| *last < root) {
+            result.push(root);
+        }
+    };
+    for pair in points.windows(2) {
+        let (mut a, mut b) = (pair[0], pair[1]);
+        let (fa, fb) = (evaluate(coefficients, a), evaluate(coefficients, b));
+        if fa.is_zero() {
+            push(a);
+            continue;
+        }
+        if fb.is_zero() || fa.signum() == fb.signum() {
+            continue;
+        }
+        for _ in 0..ITERATIONS {
+            let c = (a + b) / (T::one() + T::one());
+            if c <= a || c >= b {
+                break;
+            }
+            let fc = evaluate(coefficients, c);
+            if fc.is_zero() {
+                (a, b) = (c, c);
+                break;
+            }
+            if fc.signum() == fa.signum() {
+                a = c;
+            } else {
+                b = c;
+            }
+        }
+        push((a + b) / (T::one() + T::one()));
+    }
+    if evaluate(coefficients, upper).is_zero() {
+        push(upper);
+    }
+    result
+}
+
+fn combine<T, F>(one: &[T], other: &[T], operation: F) -> Vec<T>
+where
+    T: Float,
+    F: Fn(T, T) -> T,
+{
+    (0..one.len().max(other.len()))
+        .map(|i| {
+            let one = one.get(i).copied().unwrap_or_else(T::zero);
+            let other = other.get(i).copied().unwrap_or_else(T::zero);
+            operation(one, other)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{evaluate, integrate, multiply, roots};
+
+    #[test]
+    fn find() {
+        let coefficients = multiply(&multiply(&[-0.2, 1.0], &[-0.5, 1.0]), &[-0.9, 1.0]);
+        let values = roots(&coefficients, (0.0, 1.0));
+        assert_eq!(values.len(), 3);
+        for (value, expected) in values.into_iter().zip([0.2, 0.5, 0.9]) {
+            assert::close(value, expected, 1e-12);
+        }
+        assert_eq!(roots(&coefficients, (0.3, 0.6)).len(), 1);
+        assert_eq!(roots(&[1.0, 0.0, 1.0], (-10.0, 10.0)), vec![]);
+        assert_eq!(roots(&[0.0, 0.0, 1.0], (-1.0, 1.0)), vec![0.0]);
+        assert_eq!(roots(&[-1.0, 1.0, 0.0], (0.0, 1.0)), vec![1.0]);
+        assert::close(evaluate(&[1.0, 2.0, 3.0], 2.0), 17.0, 0.0);
+        assert::close(integrate(&[1.0, 2.0, 3.0]), 3.0, 0.0);
+    }
+}
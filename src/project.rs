@@ -0,0 +1,18 @@
+use num_traits::Float;
+
+/// A curve onto which a point can be projected.
+pub trait Project<T: Float> {
+    /// Perform the calculation.
+    fn project(&self, point: (T, T)) -> Projection<T>;
+}
+
+/// A projection of a point onto a curve.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Projection<T> {
+    /// The parameter of the closest point.
+    pub parameter: T,
+    /// The closest point.
+    pub point: (T, T),
+    /// The distance to the closest point.
+    pub distance: T,
+}
@@ -0,0 +1,269 @@
+//! Rasterization.
+//!
+//! The coverage of each pixel is computed exactly for the flattened outline by accumulating the
+//! signed areas to the right of each line and then integrating along each row.
+
+// Reference:
+// https://github.com/raphlinus/font-rs
+
+use num_traits::Float;
+
+use crate::evaluate::Evaluate;
+use crate::path::{Path, Segment};
+
+// The tolerance below which deviations are invisible in the accumulated coverage.
+const MINIMUM: f64 = 1.0 / 1024.0;
+
+/// A rasterizer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rasterizer {
+    width: usize,
+    height: usize,
+    accumulation: Vec<f32>,
+}
+
+impl Rasterizer {
+    /// Create an instance.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            accumulation: vec![0.0; (width + 2) * height],
+        }
+    }
+
+    /// Fill a path.
+    ///
+    /// The coordinates are in pixels with the y-axis pointing down. Curves are flattened so that
+    /// the resulting lines deviate by at most the tolerance, which is raised to 1/1024 of a pixel
+    /// if it is smaller or not a number. Open contours are closed with lines.
+    pub fn fill<T: Float>(&mut self, path: &Path<T>, tolerance: T) {
+        let tolerance = tolerance.max(T::from(MINIMUM).unwrap());
+        for contour in path {
+            let mut position = contour.start();
+            for segment in contour {
+                let count = count(segment, tolerance);
+                for i in 1..=count {
+                    let point = if i == count {
+                        segment.end()
+                    } else {
+                        segment.evaluate(T::from(i).unwrap() / T::from(count).unwrap())
+                    };
+                    self.line(convert(position), convert(point));
+                    position = point;
+                }
+            }
+            self.line(convert(position), convert(contour.start()));
+        }
+    }
+
+    /// Add a line.
+    ///
+    /// Lines going down add coverage and the ones going up remove it.
+    pub fn line(&mut self, from: (f32, f32), to: (f32, f32)) {
+        if from.1 == to.1 {
+            return;
+        }
+        // Lines crossing the vertical boundaries are split so that the parts outside can be
+        // clamped onto them.
+        for boundary in [0.0, self.width as f32] {
+            if (from.0 - boundary) * (to.0 - boundary) < 0.0 {
+                let t = (boundary - from.0) / (to.0 - from.0);
+                let middle = (boundary, from.1 + t * (to.1 - from.1));
+                self.line(from, middle);
+                self.line(middle, to);
+                return;
+            }
+        }
+        let (sign, from, to) = if from.1 < to.1 {
+            (1.0, from, to)
+        } else {
+            (-1.0, to, from)
+        };
+        let slope = (to.0 - from.0) / (to.1 - from.1);
+        let (top, bottom) = (from.1.max(0.0), to.1.min(self.height as f32));
+        if top >= bottom {
+            return;
+        }
+        let stride = self.width + 2;
+        let limit = self.width as f32;
+        let mut x = from.0 + (top - from.1) * slope;
+        for y in (top as usize)..(bottom.ceil() as usize) {
+            let row = &mut self.accumulation[y * stride..(y + 1) * stride];
+            let dy = (y as f32 + 1.0).min(bottom) - (y as f32).max(top);
+            let next = x + slope * dy;
+            let area = sign * dy;
+            let (x0, x1) = (x.min(next).clamp(0.0, limit), x.max(next).clamp(0.0, limit));
+            let (floor, ceil) = (x0.floor(), x1.ceil());
+            let (i0, i1) = (floor as usize, ceil as usize);
+            if i1 <= i0 + 1 {
+                let middle = 0.5 * (x0 + x1) - floor;
+                row[i0] += area * (1.0 - middle);
+                row[i0 + 1] += area * middle;
+            } else {
+                let scale = 1.0 / (x1 - x0);
+                let first = x0 - floor;
+                let head = 0.5 * scale * (1.0 - first) * (1.0 - first);
+                let last = x1 - ceil + 1.0;
+                let tail = 0.5 * scale * last * last;
+                row[i0] += area * head;
+                if i1 == i0 + 2 {
+                    row[i0 + 1] += area * (1.0 - head - tail);
+                } else {
+                    let second = scale * (1.5 - first);
+                    row[i0 + 1] += area * (second - head);
+                    for value in &mut row[(i0 + 2)..(i1 - 1)] {
+                        *value += area * scale;
+                    }
+                    let before = second + (i1 - i0 - 3) as f32 * scale;
+                    row[i1 - 1] += area * (1.0 - before - tail);
+                }
+                row[i1] += area * tail;
+            }
+            x = next;
+        }
+    }
+
+    /// Compute the coverage of each pixel row by row.
+    ///
+    /// Overlapping parts are filled according to the nonzero rule.
+    pub fn coverage(&self) -> Vec<u8> {
+        let stride = self.width + 2;
+        let mut coverage = Vec::with_capacity(self.width * self.height);
+        for row in self.accumulation.chunks(stride) {
+            let mut sum = 0.0;
+            for value in &row[..self.width] {
+                sum += value;
+                coverage.push((sum.abs().min(1.0) * 255.0).round() as u8);
+            }
+        }
+        coverage
+    }
+
+    /// Return the width.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Return the height.
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+}
+
+// The deviation of a curve from its chords is bounded by the second differences of the control
+// points.
+fn count<T: Float>(segment: &Segment<T>, tolerance: T) -> usize {
+    let (x, y, degree): (&[T], &[T], usize) = match segment {
+        Segment::Linear(..) => return 1,
+        Segment::Quadratic(x, y) => (&x[..], &y[..], 2),
+        Segment::Cubic(x, y) => (&x[..], &y[..], 3),
+    };
+    let maximum = (0..(x.len() - 2))
+        .map(|i| {
+            let u = x[i] - x[i + 1] - x[i + 1] + x[i + 2];
+            let v = y[i] - y[i + 1] - y[i + 1] + y[i + 2];
+            u.hypot(v)
+        })
+        .fold(T::zero(), T::max);
+    let factor = T::from(degree * (degree - 1)).unwrap() / T::from(8).unwrap();
+    (factor * maximum / tolerance)
+        .sqrt()
+        .ceil()
+        .to_usize()
+        .unwrap_or(1)
+        .max(1)
+}
+
+#[inline]
+fn convert<T: Float>((x, y): (T, T)) -> (f32, f32) {
+    (x.to_f32().unwrap(), y.to_f32().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::path::Path;
+    use crate::raster::Rasterizer;
+
+    #[test]
+    fn square() {
+        let mut path = Path::new();
+        path.move_to((1.0, 1.0));
+        path.line_to((3.0, 1.0));
+        path.line_to((3.0, 3.0));
+        path.line_to((1.0, 3.0));
+        path.close();
+        let mut rasterizer = Rasterizer::new(4, 4);
+        rasterizer.fill(&path, 0.1);
+        #[rustfmt::skip]
+        let expected = vec![
+            0, 0, 0, 0,
+            0, 255, 255, 0,
+            0, 255, 255, 0,
+            0, 0, 0, 0,
+        ];
+        assert_eq!(rasterizer.coverage(), expected);
+    }
+
+    #[test]
+    fn partial() {
+        let mut path = Path::new();
+        path.move_to((0.5, 0.0));
+        path.line_to((0.5, 1.0));
+        path.line_to((6.5, 1.0));
+        path.line_to((0.5, 0.0));
+        let mut rasterizer = Rasterizer::new(4, 1);
+        rasterizer.fill(&path, 0.1);
+        let coverage = rasterizer.coverage();
+        // The areas below the line y = (x - 0.5) / 6 within each pixel.
+        let expected = [0.5 - 0.25 / 12.0, 5.0 / 6.0, 4.0 / 6.0, 3.0 / 6.0];
+        for (value, expected) in coverage.into_iter().zip(expected) {
+            assert::close(value as f64, expected * 255.0, 1.0);
+        }
+    }
+
+    #[test]
+    fn circle() {
+        let k = 0.5522847498307936 * 10.0;
+        let mut path = Path::new();
+        path.move_to((26.0, 16.0));
+        path.cubic_to((26.0, 16.0 + k), (16.0 + k, 26.0), (16.0, 26.0));
+        path.cubic_to((16.0 - k, 26.0), (6.0, 16.0 + k), (6.0, 16.0));
+        path.cubic_to((6.0, 16.0 - k), (16.0 - k, 6.0), (16.0, 6.0));
+        path.cubic_to((16.0 + k, 6.0), (26.0, 16.0 - k), (26.0, 16.0));
+        path.close();
+        let mut rasterizer = Rasterizer::new(32, 32);
+        rasterizer.fill(&path, 0.01);
+        let coverage = rasterizer.coverage();
+        let total = coverage.iter().map(|value| *value as f64).sum::<f64>() / 255.0;
+        assert::close(total, 100.0 * std::f64::consts::PI, 0.5);
+        assert_eq!(coverage[16 * 32 + 16], 255);
+        assert_eq!(coverage[0], 0);
+        for tolerance in [0.0, -1.0, f64::NAN] {
+            let mut rasterizer = Rasterizer::new(32, 32);
+            rasterizer.fill(&path, tolerance);
+            let total = rasterizer
+                .coverage()
+                .iter()
+                .map(|value| *value as f64)
+                .sum::<f64>()
+                / 255.0;
+            assert::close(total, 100.0 * std::f64::consts::PI, 0.5);
+        }
+    }
+
+    #[test]
+    fn clip() {
+        let mut path = Path::new();
+        path.move_to((-2.0, -2.0));
+        path.line_to((6.0, -2.0));
+        path.line_to((6.0, 1.0));
+        path.line_to((-2.0, 1.0));
+        path.close();
+        let mut rasterizer = Rasterizer::new(2, 2);
+        rasterizer.fill(&path, 0.1);
+        assert_eq!(rasterizer.coverage(), vec![255, 255, 0, 0]);
+    }
+}
@@ -21,3 +21,33 @@ where
         (self.0.reduce(), self.1.reduce())
     }
 }
+
+impl<T, U> Reduce<T> for (U, U, U)
+where
+    T: Float,
+    U: Reduce<T>,
+{
+    type Target = (
+        <U as Reduce<T>>::Target,
+        <U as Reduce<T>>::Target,
+        <U as Reduce<T>>::Target,
+    );
+
+    #[inline]
+    fn reduce(&self) -> Self::Target {
+        (self.0.reduce(), self.1.reduce(), self.2.reduce())
+    }
+}
+
+impl<T, U, const N: usize> Reduce<T> for [U; N]
+where
+    T: Float,
+    U: Reduce<T>,
+{
+    type Target = [<U as Reduce<T>>::Target; N];
+
+    #[inline]
+    fn reduce(&self) -> Self::Target {
+        self.each_ref().map(|curve| curve.reduce())
+    }
+}
@@ -0,0 +1,51 @@
+use num_traits::Float;
+
+/// A curve that can be reparameterized.
+pub trait Reparameterize<T: Float>: Sized {
+    /// Perform the calculation.
+    ///
+    /// The result maps the unit interval onto the given one of the original curve. The interval
+    /// is not restricted to the unit one, and it can be reversed.
+    fn reparameterize(&self, start: T, end: T) -> Self;
+}
+
+impl<T, U> Reparameterize<T> for (U, U)
+where
+    T: Float,
+    U: Reparameterize<T>,
+{
+    #[inline]
+    fn reparameterize(&self, start: T, end: T) -> Self {
+        (
+            self.0.reparameterize(start, end),
+            self.1.reparameterize(start, end),
+        )
+    }
+}
+
+impl<T, U> Reparameterize<T> for (U, U, U)
+where
+    T: Float,
+    U: Reparameterize<T>,
+{
+    #[inline]
+    fn reparameterize(&self, start: T, end: T) -> Self {
+        (
+            self.0.reparameterize(start, end),
+            self.1.reparameterize(start, end),
+            self.2.reparameterize(start, end),
+        )
+    }
+}
+
+impl<T, U, const N: usize> Reparameterize<T> for [U; N]
+where
+    T: Float,
+    U: Reparameterize<T>,
+{
+    #[inline]
+    fn reparameterize(&self, start: T, end: T) -> Self {
+        self.each_ref()
+            .map(|curve| curve.reparameterize(start, end))
+    }
+}
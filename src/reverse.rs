@@ -0,0 +1,40 @@
+use num_traits::Float;
+
+/// A curve that can be reversed.
+pub trait Reverse<T: Float>: Sized {
+    /// Perform the calculation.
+    fn reverse(&self) -> Self;
+}
+
+impl<T, U> Reverse<T> for (U, U)
+where
+    T: Float,
+    U: Reverse<T>,
+{
+    #[inline]
+    fn reverse(&self) -> Self {
+        (self.0.reverse(), self.1.reverse())
+    }
+}
+
+impl<T, U> Reverse<T> for (U, U, U)
+where
+    T: Float,
+    U: Reverse<T>,
+{
+    #[inline]
+    fn reverse(&self) -> Self {
+        (self.0.reverse(), self.1.reverse(), self.2.reverse())
+    }
+}
+
+impl<T, U, const N: usize> Reverse<T> for [U; N]
+where
+    T: Float,
+    U: Reverse<T>,
+{
+    #[inline]
+    fn reverse(&self) -> Self {
+        self.each_ref().map(|curve| curve.reverse())
+    }
+}
@@ -0,0 +1,524 @@
+//! Strokes.
+
+use num_traits::Float;
+
+use crate::arc::goodness::SampledRadialAbsolute;
+use crate::arc::Arc;
+use crate::bezier::{Cubic, Linear};
+use crate::conversion::Conversion;
+use crate::evaluate::Evaluate;
+use crate::frame::Frame;
+use crate::goodness::Goodness;
+use crate::offset::goodness::SampledAbsolute;
+use crate::offset::Offset;
+use crate::path::{Path, Segment};
+use crate::reverse::Reverse;
+use crate::subdivide::Subdivide;
+
+// Offset curves cannot be approximated within an arbitrary tolerance near cusps, so the number of
+// subdivisions is bounded, and what is left once the bound is reached is flattened into lines,
+// which are also bounded in number.
+const SUBDIVISION: usize = 256;
+const FLATTENING: usize = 8;
+
+/// A join of two consecutive segments.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Join<T> {
+    /// A sharp corner, which turns into a bevel when the ratio of its length to the width exceeds
+    /// the given limit.
+    Miter(T),
+    /// A circular arc.
+    Round,
+    /// A straight line.
+    Bevel,
+}
+
+/// A cap of an open contour.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cap {
+    /// A straight line through the endpoint.
+    Butt,
+    /// A half circle around the endpoint.
+    Round,
+    /// A half square around the endpoint.
+    Square,
+}
+
+/// A stroke.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stroke<T> {
+    width: T,
+    join: Join<T>,
+    cap: Cap,
+    tolerance: T,
+}
+
+impl<T: Float + Default> Stroke<T> {
+    /// Create an instance.
+    ///
+    /// The tolerance dictates the maximum distance between the outline and the ideal one, which
+    /// is relevant for curved segments and round joins and caps.
+    #[inline]
+    pub fn new(width: T, join: Join<T>, cap: Cap, tolerance: T) -> Self {
+        Self {
+            width,
+            join,
+            cap,
+            tolerance,
+        }
+    }
+
+    /// Outline a curve.
+    ///
+    /// The curve is treated as an open contour consisting of a single segment.
+    #[inline]
+    pub fn outline_curve(&self, curve: impl Into<Segment<T>>) -> Path<T> {
+        self.outline(&Path::from(curve.into()))
+    }
+
+    /// Outline a path.
+    ///
+    /// Each closed contour results in two contours going in the opposite directions, and each
+    /// open one results in a single contour. The outline is to be filled using the nonzero rule.
+    pub fn outline(&self, path: &Path<T>) -> Path<T> {
+        let mut outline = Path::new();
+        for contour in path {
+            let forward = contour
+                .iter()
+                .filter(|segment| !is_degenerate(segment))
+                .copied()
+                .collect::<Vec<_>>();
+            if forward.is_empty() {
+                if !contour.is_closed() {
+                    self.dot(&mut outline, contour.start());
+                }
+                continue;
+            }
+            let backward = forward
+                .iter()
+                .rev()
+                .map(Reverse::reverse)
+                .collect::<Vec<_>>();
+            if contour.is_closed() {
+                for segments in [&forward, &backward] {
+                    outline
+                        .move_to(self.shift(segments[0].start(), segments[0].tangent(T::zero())));
+                    self.side(&mut outline, segments);
+                    let last = &segments[segments.len() - 1];
+                    self.join(
+                        &mut outline,
+                        last.end(),
+                        last.tangent(T::one()),
+                        segments[0].tangent(T::zero()),
+                    );
+                    outline.close();
+                }
+            } else {
+                outline.move_to(self.shift(forward[0].start(), forward[0].tangent(T::zero())));
+                for segments in [&forward, &backward] {
+                    self.side(&mut outline, segments);
+                    let last = &segments[segments.len() - 1];
+                    self.cap(&mut outline, last.end(), last.tangent(T::one()));
+                }
+                outline.close();
+            }
+        }
+        outline
+    }
+
+    fn side(&self, outline: &mut Path<T>, segments: &[Segment<T>]) {
+        for (i, segment) in segments.iter().enumerate() {
+            if i > 0 {
+                let previous = &segments[i - 1];
+                self.join(
+                    outline,
+                    segment.start(),
+                    previous.tangent(T::one()),
+                    segment.tangent(T::zero()),
+                );
+            }
+            self.offset(outline, segment);
+        }
+    }
+
+    fn offset(&self, outline: &mut Path<T>, segment: &Segment<T>) {
+        let point = self.shift(segment.end(), segment.tangent(T::one()));
+        let distance = self.width / (T::one() + T::one());
+        let goodness = SampledAbsolute::new(self.tolerance, T::max_value(), SUBDIVISION);
+        match *segment {
+            Segment::Linear(..) => outline.line_to(point),
+            Segment::Quadratic(x, y) => {
+                let offset = Offset::new((x, y), distance);
+                self.convert(outline, offset.approximate(goodness), point);
+            }
+            Segment::Cubic(x, y) => {
+                let offset = Offset::new((x, y), distance);
+                self.convert(outline, offset.approximate(goodness), point);
+            }
+        }
+    }
+
+    fn join(&self, outline: &mut Path<T>, point: (T, T), incoming: (T, T), outgoing: (T, T)) {
+        let target = self.shift(point, outgoing);
+        let cross = incoming.0 * outgoing.1 - incoming.1 * outgoing.0;
+        let dot = incoming.0 * outgoing.0 + incoming.1 * outgoing.1;
+        if cross.abs() <= T::epsilon() && dot > T::zero() {
+            if outline.position() != target {
+                outline.line_to(target);
+            }
+            return;
+        }
+        if cross > T::zero() {
+            outline.line_to(point);
+            outline.line_to(target);
+            return;
+        }
+        match self.join {
+            Join::Miter(limit) if (dot + T::one()) * limit * limit >= T::from(2).unwrap() => {
+                let scale = self.width / (T::one() + T::one()) / (T::one() + dot);
+                let (x, y) = (-incoming.1 - outgoing.1, incoming.0 + outgoing.0);
+                outline.line_to((point.0 + scale * x, point.1 + scale * y));
+                outline.line_to(target);
+            }
+            Join::Round => {
+                let angle = cross.atan2(dot);
+                self.arc(outline, point, normal(incoming), angle, target);
+            }
+            _ => outline.line_to(target),
+        }
+    }
+
+    fn cap(&self, outline: &mut Path<T>, point: (T, T), tangent: (T, T)) {
+        let target = self.shift(point, (-tangent.0, -tangent.1));
+        let half = self.width / (T::one() + T::one());
+        match self.cap {
+            Cap::Butt => outline.line_to(target),
+            Cap::Round => {
+                let angle = -T::from(std::f64::consts::PI).unwrap();
+                self.arc(outline, point, normal(tangent), angle, target);
+            }
+            Cap::Square => {
+                let (x, y) = outline.position();
+                outline.line_to((x + half * tangent.0, y + half * tangent.1));
+                outline.line_to((target.0 + half * tangent.0, target.1 + half * tangent.1));
+                outline.line_to(target);
+            }
+        }
+    }
+
+    fn dot(&self, outline: &mut Path<T>, point: (T, T)) {
+        let tangent = (T::one(), T::zero());
+        let start = self.shift(point, tangent);
+        match self.cap {
+            Cap::Butt => return,
+            Cap::Round => {
+                let angle = T::from(std::f64::consts::TAU).unwrap();
+                outline.move_to(start);
+                self.arc(outline, point, normal(tangent), angle, start);
+            }
+            Cap::Square => {
+                let half = self.width / (T::one() + T::one());
+                outline.move_to((point.0 + half, point.1 - half));
+                outline.line_to((point.0 + half, point.1 + half));
+                outline.line_to((point.0 - half, point.1 + half));
+                outline.line_to((point.0 - half, point.1 - half));
+            }
+        }
+        outline.close();
+    }
+
+    fn arc(&self, outline: &mut Path<T>, center: (T, T), from: (T, T), angle: T, target: (T, T)) {
+        let radius = self.width / (T::one() + T::one());
+        let arc = Arc::new(
+            center,
+            (radius, radius),
+            T::zero(),
+            from.1.atan2(from.0),
+            angle,
+        );
+        let goodness = SampledRadialAbsolute::new(self.tolerance, T::max_value(), SUBDIVISION);
+        let conversion = Conversion::<T, _, (Cubic<T>, Cubic<T>), _>::new(arc, goodness);
+        self.convert(outline, conversion, target);
+    }
+
+    fn convert<U, V, W>(
+        &self,
+        outline: &mut Path<T>,
+        mut conversion: Conversion<T, U, V, W>,
+        target: (T, T),
+    ) where
+        U: Clone + Evaluate<T, Target = (T, T)> + Into<V> + Subdivide<T>,
+        V: Into<Segment<T>>,
+        W: Goodness<(U, V)>,
+    {
+        let mut curves = conversion.by_ref().map(Into::into).collect::<Vec<_>>();
+        for part in conversion.remainder() {
+            self.flatten(&mut curves, part.clone(), 0);
+        }
+        push(outline, curves, target);
+    }
+
+    fn flatten<U>(&self, curves: &mut Vec<Segment<T>>, part: U, depth: usize)
+    where
+        U: Evaluate<T, Target = (T, T)> + Subdivide<T>,
+    {
+        let ((x, y), (u, v)) = (part.evaluate(T::zero()), part.evaluate(T::one()));
+        let deviation = [0.25, 0.5, 0.75]
+            .into_iter()
+            .map(|t| {
+                let t = T::from(t).unwrap();
+                let (a, b) = part.evaluate(t);
+                (a - x - t * (u - x)).hypot(b - y - t * (v - y))
+            })
+            .fold(T::zero(), T::max);
+        if depth == FLATTENING || deviation <= self.tolerance {
+            curves.push(Segment::from((Linear::new(x, u), Linear::new(y, v))));
+            return;
+        }
+        let (head, tail) = part.subdivide(T::from(0.5).unwrap());
+        self.flatten(curves, head, depth + 1);
+        self.flatten(curves, tail, depth + 1);
+    }
+
+    fn shift(&self, point: (T, T), tangent: (T, T)) -> (T, T) {
+        let half = self.width / (T::one() + T::one());
+        let (x, y) = normal(tangent);
+        (point.0 + half * x, point.1 + half * y)
+    }
+}
+
+fn is_degenerate<T: Float>(segment: &Segment<T>) -> bool {
+    let (x, y) = segment.start();
+    match *segment {
+        Segment::Linear(u, v) => u.iter().all(|u| *u == x) && v.iter().all(|v| *v == y),
+        Segment::Quadratic(u, v) => u.iter().all(|u| *u == x) && v.iter().all(|v| *v == y),
+        Segment::Cubic(u, v) => u.iter().all(|u| *u == x) && v.iter().all(|v| *v == y),
+    }
+}
+
+fn normal<T: Float>((x, y): (T, T)) -> (T, T) {
+    (-y, x)
+}
+
+fn push<T: Float>(outline: &mut Path<T>, mut curves: Vec<Segment<T>>, target: (T, T)) {
+    let count = curves.len();
+    for (i, curve) in curves.iter_mut().enumerate() {
+        let (x, y) = outline.position();
+        match curve {
+            Segment::Linear(u, v) => (u[0], v[0]) = (x, y),
+            Segment::Quadratic(u, v) => (u[0], v[0]) = (x, y),
+            Segment::Cubic(u, v) => (u[0], v[0]) = (x, y),
+        }
+        if i + 1 == count {
+            match curve {
+                Segment::Linear(u, v) => (u[1], v[1]) = target,
+                Segment::Quadratic(u, v) => (u[2], v[2]) = target,
+                Segment::Cubic(u, v) => (u[3], v[3]) = target,
+            }
+        }
+        outline.push(*curve);
+    }
+    if count == 0 {
+        outline.line_to(target);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bezier::Cubic;
+    use crate::evaluate::Evaluate;
+    use crate::frame::Frame;
+    use crate::path::{Path, Segment};
+    use crate::project::Project;
+    use crate::stroke::{Cap, Join, Stroke};
+
+    #[test]
+    fn cusp() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.cubic_to((1.0, 1.0), (0.0, 1.0), (1.0, 0.0));
+        let outline = Stroke::new(0.2, Join::Round, Cap::Round, 0.0).outline(&path);
+        assert_eq!(outline.len(), 1);
+        assert!(outline[0].is_closed());
+        assert_eq!(outline[0].start(), outline[0][outline[0].len() - 1].end());
+
+        let curve = (
+            Cubic::new(0.0, 1.0, 0.0, 1.0),
+            Cubic::new(0.0, 1.0, 1.0, 0.0),
+        );
+        // A tiny tolerance exhausts the subdivisions, and the rest is flattened.
+        for tolerance in [1e-12, 1e-3] {
+            let outline = Stroke::new(0.2, Join::Round, Cap::Butt, tolerance).outline(&path);
+            assert!(deviation(&outline, curve, 0.1) <= 1e-3);
+        }
+    }
+
+    #[test]
+    fn line() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.line_to((10.0, 0.0));
+        let outline = Stroke::new(2.0, Join::Bevel, Cap::Butt, 0.1).outline(&path);
+        assert_eq!(outline.len(), 1);
+        assert_eq!(
+            corners(&outline[0]),
+            vec![
+                (0.0, 1.0),
+                (10.0, 1.0),
+                (10.0, -1.0),
+                (0.0, -1.0),
+                (0.0, 1.0)
+            ],
+        );
+        let outline = Stroke::new(2.0, Join::Bevel, Cap::Square, 0.1).outline(&path);
+        assert_eq!(
+            corners(&outline[0]),
+            vec![
+                (0.0, 1.0),
+                (10.0, 1.0),
+                (11.0, 1.0),
+                (11.0, -1.0),
+                (10.0, -1.0),
+                (0.0, -1.0),
+                (-1.0, -1.0),
+                (-1.0, 1.0),
+                (0.0, 1.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn joins() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.line_to((10.0, 0.0));
+        path.line_to((10.0, -10.0));
+        let outline = Stroke::new(2.0, Join::Miter(4.0), Cap::Butt, 0.1).outline(&path);
+        assert_eq!(
+            corners(&outline[0]),
+            vec![
+                (0.0, 1.0),
+                (10.0, 1.0),
+                (11.0, 1.0),
+                (11.0, 0.0),
+                (11.0, -10.0),
+                (9.0, -10.0),
+                (9.0, 0.0),
+                (10.0, 0.0),
+                (10.0, -1.0),
+                (0.0, -1.0),
+                (0.0, 1.0),
+            ],
+        );
+        let outline = Stroke::new(2.0, Join::Miter(1.0), Cap::Butt, 0.1).outline(&path);
+        assert_eq!(corners(&outline[0])[2], (11.0, 0.0));
+        let outline = Stroke::new(2.0, Join::Round, Cap::Butt, 0.1).outline(&path);
+        assert!(matches!(outline[0][1], Segment::Cubic(..)));
+        for segment in outline.segments() {
+            if let Segment::Cubic(..) = segment {
+                let (x, y) = segment.start();
+                assert::close((x - 10.0).hypot(y), 1.0, 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn closed() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.line_to((10.0, 0.0));
+        path.line_to((10.0, 10.0));
+        path.line_to((0.0, 10.0));
+        path.close();
+        let outline = Stroke::new(2.0, Join::Miter(4.0), Cap::Butt, 0.1).outline(&path);
+        assert_eq!(outline.len(), 2);
+        assert!(corners(&outline[0]).contains(&(10.0, 1.0)));
+        assert!(corners(&outline[0]).contains(&(9.0, 0.0)));
+        assert!(corners(&outline[1]).contains(&(-1.0, -1.0)));
+        assert!(corners(&outline[1]).contains(&(11.0, 11.0)));
+    }
+
+    #[test]
+    fn curve() {
+        let mut path = Path::new();
+        path.move_to((0.0, 0.0));
+        path.cubic_to((0.0, 50.0), (90.0, 0.0), (100.0, 0.0));
+        let outline = Stroke::new(10.0, Join::Round, Cap::Round, 0.01).outline(&path);
+        assert_eq!(outline.len(), 1);
+        let contour = &outline[0];
+        for pair in contour.windows(2) {
+            assert_eq!(pair[0].end(), pair[1].start());
+        }
+        assert_eq!(contour[0].start(), (-5.0, 0.0));
+        assert_eq!(contour[contour.len() - 1].end(), (-5.0, 0.0));
+        let curve = (
+            Cubic::new(0.0, 0.0, 90.0, 100.0),
+            Cubic::new(0.0, 50.0, 0.0, 0.0),
+        );
+        let stroke = Stroke::new(10.0, Join::Round, Cap::Round, 0.01);
+        assert_eq!(stroke.outline_curve(curve), outline);
+    }
+
+    #[test]
+    fn single() {
+        let curve = (
+            Cubic::new(0.0, 1.0, 0.0, 1.0),
+            Cubic::new(0.0, 1.0, 1.0, 0.0),
+        );
+        let stroke = Stroke::new(0.2, Join::Round, Cap::Round, 1e-12);
+        let outline = stroke.outline_curve(curve);
+        assert_eq!(outline.len(), 1);
+        assert!(deviation(&outline, curve, 0.1) <= 1e-3);
+    }
+
+    #[test]
+    fn dot() {
+        let mut path = Path::new();
+        path.move_to((1.0, 1.0));
+        path.line_to((1.0, 1.0));
+        assert!(Stroke::new(2.0, Join::Round, Cap::Butt, 0.1)
+            .outline(&path)
+            .is_empty());
+        let outline = Stroke::new(2.0, Join::Round, Cap::Round, 0.1).outline(&path);
+        assert_eq!(outline.len(), 1);
+        assert_eq!(outline[0][0].start(), (1.0, 2.0));
+        assert_eq!(outline[0][outline[0].len() - 1].end(), (1.0, 2.0));
+    }
+
+    // The largest distance from the offset curves on both sides to the outline away from the
+    // cusp in the middle, where the offset curves are not smooth.
+    fn deviation(outline: &Path<f64>, curve: (Cubic<f64>, Cubic<f64>), distance: f64) -> f64 {
+        let mut maximum = 0.0f64;
+        for i in 0..=100 {
+            let t = i as f64 / 100.0;
+            if (t - 0.5).abs() < 0.1 {
+                continue;
+            }
+            let (x, y) = curve.evaluate(t);
+            let (u, v) = curve.normal(t);
+            for sign in [-1.0, 1.0] {
+                let point = (x + sign * distance * u, y + sign * distance * v);
+                let mut closest = f64::INFINITY;
+                for segment in outline.segments() {
+                    let ((x0, y0), (x1, y1)) = segment.bounds();
+                    let dx = (x0 - point.0).max(point.0 - x1).max(0.0);
+                    let dy = (y0 - point.1).max(point.1 - y1).max(0.0);
+                    if dx.hypot(dy) < closest {
+                        closest = closest.min(segment.project(point).distance);
+                    }
+                }
+                maximum = maximum.max(closest);
+            }
+        }
+        maximum
+    }
+
+    fn corners(contour: &[Segment<f64>]) -> Vec<(f64, f64)> {
+        let mut points = vec![contour[0].start()];
+        points.extend(contour.iter().map(|segment| {
+            let (x, y) = segment.end();
+            (x + 0.0, y + 0.0)
+        }));
+        points
+    }
+}
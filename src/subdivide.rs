@@ -4,6 +4,28 @@ use num_traits::Float;
 pub trait Subdivide<T: Float>: Sized {
     /// Perform the calculation.
     fn subdivide(&self, t: T) -> (Self, Self);
+
+    /// Extract the part between two parameters.
+    ///
+    /// If the end does not exceed the start, the part degenerates into the point at the start.
+    fn extract(&self, start: T, end: T) -> Self
+    where
+        Self: Clone,
+    {
+        let (zero, one) = (T::zero(), T::one());
+        debug_assert!(zero <= start && start <= one && zero <= end && end <= one);
+        let end = end.max(start);
+        let tail = if start > zero {
+            self.subdivide(start).1
+        } else {
+            self.clone()
+        };
+        if end < one {
+            tail.subdivide((end - start) / (one - start)).0
+        } else {
+            tail
+        }
+    }
 }
 
 impl<T, U> Subdivide<T> for (U, U)
@@ -18,3 +40,33 @@ where
         ((x.0, y.0), (x.1, y.1))
     }
 }
+
+impl<T, U> Subdivide<T> for (U, U, U)
+where
+    T: Float,
+    U: Subdivide<T>,
+{
+    #[inline]
+    fn subdivide(&self, t: T) -> (Self, Self) {
+        let x = self.0.subdivide(t);
+        let y = self.1.subdivide(t);
+        let z = self.2.subdivide(t);
+        ((x.0, y.0, z.0), (x.1, y.1, z.1))
+    }
+}
+
+impl<T, U, const N: usize> Subdivide<T> for [U; N]
+where
+    T: Float,
+    U: Subdivide<T>,
+{
+    fn subdivide(&self, t: T) -> (Self, Self) {
+        let mut parts = self.each_ref().map(|curve| {
+            let (head, tail) = curve.subdivide(t);
+            (Some(head), Some(tail))
+        });
+        let head = std::array::from_fn(|i| parts[i].0.take().unwrap());
+        let tail = std::array::from_fn(|i| parts[i].1.take().unwrap());
+        (head, tail)
+    }
+}
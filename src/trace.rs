@@ -2,6 +2,7 @@ use std::marker::PhantomData;
 
 use num_traits::Float;
 
+use crate::difference::Difference;
 use crate::Evaluate;
 
 /// A trace of a curve.
@@ -47,12 +48,74 @@ where
     }
 }
 
+/// A trace of a curve using forward differences.
+///
+/// The points are the same as the ones of [`Trace`] up to rounding errors, but each one takes
+/// only a few additions to compute. The last point is evaluated directly.
+#[derive(Clone, Debug)]
+pub struct ForwardTrace<T, U: Difference<T>>
+where
+    T: Float,
+{
+    curve: U,
+    state: U::State,
+    points: usize,
+    index: usize,
+}
+
+impl<T, U> ForwardTrace<T, U>
+where
+    T: Float,
+    U: Difference<T>,
+{
+    /// Create an instance.
+    #[inline]
+    pub fn new(curve: U, points: usize) -> Self {
+        let step = if points > 1 {
+            T::one() / T::from(points - 1).unwrap()
+        } else {
+            T::zero()
+        };
+        Self {
+            state: curve.start(step),
+            curve,
+            points,
+            index: 0,
+        }
+    }
+}
+
+impl<T, U> Iterator for ForwardTrace<T, U>
+where
+    T: Float,
+    U: Difference<T>,
+{
+    type Item = <U as Evaluate<T>>::Target;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.points {
+            return None;
+        }
+        self.index += 1;
+        if self.index > 1 && self.index == self.points {
+            return Some(self.curve.evaluate(T::one()));
+        }
+        Some(U::advance(&mut self.state))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.points - self.index;
+        (count, Some(count))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use assert;
 
     use crate::bezier::{Cubic, Linear, Quadratic};
-    use crate::trace::Trace;
+    use crate::trace::{ForwardTrace, Trace};
 
     #[test]
     fn linear() {
@@ -62,6 +125,31 @@ mod tests {
         assert_eq!(trace, Trace::new((x, y), 3).collect::<Vec<_>>());
     }
 
+    #[test]
+    fn linear_3d() {
+        let x = Linear::new(1.0, 5.0);
+        let y = Linear::new(2.0, 3.0);
+        let z = Linear::new(0.0, -4.0);
+        let trace = vec![(1.0, 2.0, 0.0), (3.0, 2.5, -2.0), (5.0, 3.0, -4.0)];
+        assert_eq!(trace, Trace::new((x, y, z), 3).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn linear_array() {
+        let curve = [
+            Linear::new(1.0, 5.0),
+            Linear::new(2.0, 3.0),
+            Linear::new(0.0, -4.0),
+            Linear::new(1.0, 1.0),
+        ];
+        let trace = vec![
+            [1.0, 2.0, 0.0, 1.0],
+            [3.0, 2.5, -2.0, 1.0],
+            [5.0, 3.0, -4.0, 1.0],
+        ];
+        assert_eq!(trace, Trace::new(curve, 3).collect::<Vec<_>>());
+    }
+
     #[test]
     fn quadratic() {
         let x = Quadratic::new(1.0, 3.0, 5.0);
@@ -105,4 +193,33 @@ mod tests {
             assert::close(trace[i].1, y, 1e-15);
         }
     }
+
+    #[test]
+    fn forward() {
+        let x = Cubic::new(1.0, 3.0, 5.0, 6.0);
+        let y = Cubic::new(2.0, 1.0, 3.0, 2.0);
+        let z = Cubic::new(1.0, 3.0, 3.0, 5.0);
+        for points in [0, 2, 3, 10, 100_000] {
+            let trace = ForwardTrace::new((x, y), points);
+            assert_eq!(trace.size_hint(), (points, Some(points)));
+            let mut count = 0;
+            for (one, other) in trace.zip(Trace::new((x, y), points)) {
+                assert::close(one.0, other.0, 1e-12);
+                assert::close(one.1, other.1, 1e-12);
+                count += 1;
+            }
+            assert_eq!(count, points);
+        }
+        assert_eq!(
+            ForwardTrace::new((x, y), 1).collect::<Vec<_>>(),
+            vec![(1.0, 2.0)],
+        );
+        let trace = ForwardTrace::new((x, y, z), 3).collect::<Vec<_>>();
+        assert_eq!(trace[0], (1.0, 2.0, 1.0));
+        assert_eq!(trace[2], (6.0, 2.0, 5.0));
+        assert_eq!(
+            ForwardTrace::new(Linear::new(1.0, 5.0), 5).collect::<Vec<_>>(),
+            vec![1.0, 2.0, 3.0, 4.0, 5.0],
+        );
+    }
 }
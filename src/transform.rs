@@ -0,0 +1,190 @@
+use num_traits::Float;
+
+/// A curve that can be transformed.
+pub trait Transform<T: Float, U> {
+    /// The resulting curve.
+    type Target;
+
+    /// Perform the calculation.
+    fn transform(&self, transformation: &U) -> Self::Target;
+}
+
+/// An affine transformation.
+///
+/// The coefficients `[a, b, c, d, e, f]` map a point `(x, y)` to
+/// `(a * x + c * y + e, b * x + d * y + f)`, which is the convention of PostScript and SVG.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine<T>([T; 6]);
+
+impl<T: Float> Affine<T> {
+    /// Create an instance.
+    #[inline]
+    pub fn new(a: T, b: T, c: T, d: T, e: T, f: T) -> Self {
+        Self([a, b, c, d, e, f])
+    }
+
+    /// Create an identity transformation.
+    #[inline]
+    pub fn identity() -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Self::new(one, zero, zero, one, zero, zero)
+    }
+
+    /// Create a translation.
+    #[inline]
+    pub fn translate(x: T, y: T) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Self::new(one, zero, zero, one, x, y)
+    }
+
+    /// Create a scaling.
+    #[inline]
+    pub fn scale(x: T, y: T) -> Self {
+        let zero = T::zero();
+        Self::new(x, zero, zero, y, zero, zero)
+    }
+
+    /// Create a counterclockwise rotation by an angle in radians.
+    #[inline]
+    pub fn rotate(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let zero = T::zero();
+        Self::new(cos, sin, -sin, cos, zero, zero)
+    }
+
+    /// Create a skewing by angles in radians along the horizontal and vertical axes.
+    #[inline]
+    pub fn skew(x: T, y: T) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Self::new(one, y.tan(), x.tan(), one, zero, zero)
+    }
+
+    /// Compose with another transformation applied afterwards.
+    pub fn then(&self, other: &Self) -> Self {
+        let [a, b, c, d, e, f] = self.0;
+        let [g, h, i, j, k, l] = other.0;
+        Self::new(
+            g * a + i * b,
+            h * a + j * b,
+            g * c + i * d,
+            h * c + j * d,
+            g * e + i * f + k,
+            h * e + j * f + l,
+        )
+    }
+
+    /// Apply to a point.
+    #[inline]
+    pub fn apply(&self, (x, y): (T, T)) -> (T, T) {
+        let [a, b, c, d, e, f] = self.0;
+        (a * x + c * y + e, b * x + d * y + f)
+    }
+}
+
+impl<T: Float> Default for Affine<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T> std::ops::Deref for Affine<T> {
+    type Target = [T; 6];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A projective transformation.
+///
+/// The rows of the matrix map a point `(x, y)` in homogeneous coordinates `(x, y, 1)` to
+/// `(X, Y, W)`, which corresponds to `(X / W, Y / W)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Projective<T>([[T; 3]; 3]);
+
+impl<T: Float> Projective<T> {
+    /// Create an instance.
+    #[inline]
+    pub fn new(matrix: [[T; 3]; 3]) -> Self {
+        Self(matrix)
+    }
+
+    /// Create an identity transformation.
+    #[inline]
+    pub fn identity() -> Self {
+        Affine::identity().into()
+    }
+
+    /// Compose with another transformation applied afterwards.
+    pub fn then(&self, other: &Self) -> Self {
+        Self(std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                (0..3).fold(T::zero(), |sum, k| sum + other.0[i][k] * self.0[k][j])
+            })
+        }))
+    }
+
+    /// Apply to a point in homogeneous coordinates.
+    #[inline]
+    pub fn apply_homogeneous(&self, point: [T; 3]) -> [T; 3] {
+        self.0
+            .map(|row| row[0] * point[0] + row[1] * point[1] + row[2] * point[2])
+    }
+
+    /// Apply to a point.
+    #[inline]
+    pub fn apply(&self, (x, y): (T, T)) -> (T, T) {
+        let [x, y, w] = self.apply_homogeneous([x, y, T::one()]);
+        (x / w, y / w)
+    }
+}
+
+impl<T: Float> Default for Projective<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: Float> From<Affine<T>> for Projective<T> {
+    #[inline]
+    fn from(transformation: Affine<T>) -> Self {
+        let [a, b, c, d, e, f] = transformation.0;
+        let (zero, one) = (T::zero(), T::one());
+        Self([[a, c, e], [b, d, f], [zero, zero, one]])
+    }
+}
+
+impl<T> std::ops::Deref for Projective<T> {
+    type Target = [[T; 3]; 3];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::transform::{Affine, Projective};
+
+    #[test]
+    fn then() {
+        let transformation = Affine::scale(2.0, 3.0).then(&Affine::translate(1.0, -1.0));
+        assert_eq!(transformation.apply((1.0, 1.0)), (3.0, 2.0));
+        let transformation = Affine::translate(1.0, -1.0).then(&Affine::scale(2.0, 3.0));
+        assert_eq!(transformation.apply((1.0, 1.0)), (4.0, 0.0));
+    }
+
+    #[test]
+    fn projective() {
+        let one = Affine::scale(2.0, 3.0);
+        let other = Affine::translate(1.0, -1.0);
+        let transformation = Projective::from(one).then(&other.into());
+        assert_eq!(transformation, one.then(&other).into());
+        let transformation = Projective::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.5, 0.0, 1.0]]);
+        assert_eq!(transformation.apply((2.0, 4.0)), (1.0, 2.0));
+    }
+}
@@ -0,0 +1,44 @@
+use num_traits::Float;
+
+/// A fill rule.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Fill {
+    /// Points with nonzero winding numbers are inside.
+    #[default]
+    NonZero,
+    /// Points with odd winding numbers are inside.
+    EvenOdd,
+}
+
+/// A shape around which a winding number can be computed.
+///
+/// For a curve, the winding number is the signed number of times it crosses the horizontal ray
+/// going from the point in the direction of the x-axis, with crossings in the direction of the
+/// y-axis counted positively. Each crossing is attributed to the lower end in the y-direction of
+/// a monotonic part, which makes endpoints shared by consecutive curves and tangencies to the ray
+/// be counted correctly. The numbers sum up to the winding number around a closed sequence of
+/// curves.
+pub trait Winding<T: Float> {
+    /// Perform the calculation.
+    fn winding(&self, point: (T, T)) -> isize;
+
+    /// Check if a point is inside according to a fill rule.
+    #[inline]
+    fn encloses(&self, point: (T, T), fill: Fill) -> bool {
+        let winding = self.winding(point);
+        match fill {
+            Fill::NonZero => winding != 0,
+            Fill::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
+
+impl<T, U> Winding<T> for [U]
+where
+    T: Float,
+    U: Winding<T>,
+{
+    fn winding(&self, point: (T, T)) -> isize {
+        self.iter().map(|curve| curve.winding(point)).sum()
+    }
+}